@@ -1,40 +1,81 @@
 use crate::Config;
+use rspotify_sdk::id::{AlbumId, PlaylistId, TrackId};
+use rspotify_sdk::params::history::{PlayedCursor, TimeRange};
+use rspotify_sdk::params::market::Market;
 use rspotify_sdk::response::audio_features::AudioFeatures;
-use rspotify_sdk::response::spotify_types::Track;
+use rspotify_sdk::response::playlist::PlaylistTrack;
+use rspotify_sdk::response::spotify_types::{FullTrack, ReleaseDate, SimplifiedTrack};
+use rspotify_sdk::response::Timestamp;
 use rspotify_sdk::RSpotify;
 use serde::Serialize;
 use serde_json::Result;
 use std::io::Write;
 
 #[derive(Serialize, Debug)]
-struct TrackWithFeatures {
+struct TrackWithFeatures<T = FullTrack> {
     #[serde(flatten)]
-    track: Track,
+    track: T,
 
     #[serde(flatten)]
     features: AudioFeatures,
 }
 
-pub async fn handle_fetch_playlist(id: &str, with_features: bool, env: Config) -> Result<()> {
+#[derive(Serialize, Debug)]
+struct PlayWithFeatures {
+    played_at: Timestamp,
+
+    #[serde(flatten)]
+    track: TrackWithFeatures,
+}
+
+fn track_ids<'a>(catalog_ids: impl IntoIterator<Item = Option<&'a str>>) -> Vec<TrackId> {
+    catalog_ids
+        .into_iter()
+        .map(|id| {
+            let id = id.expect("only catalog tracks have audio features");
+            TrackId::from_id(id).expect("spotify returned an invalid track id")
+        })
+        .collect()
+}
+
+fn report_skipped_items(items: &[PlaylistTrack]) {
+    for item in items {
+        if let Some(local) = item.local_file() {
+            eprintln!("skipping local file: {}", local.title);
+        } else if let Some(episode) = item.track.as_ref().and_then(|item| item.as_episode()) {
+            eprintln!("skipping episode: {}", episode.episode.name);
+        } else if item.track.is_none() {
+            eprintln!("skipping removed item added at {:?}", item.added_at);
+        }
+    }
+}
+
+pub async fn handle_fetch_playlist(
+    id: &PlaylistId,
+    market: Option<Market>,
+    with_features: bool,
+    env: Config,
+) -> Result<()> {
     let client = RSpotify::new(env.client_id, env.client_secret, Some("user"), Some("")).await;
-    let data = client.get_playlist_tracks(id).await;
+    let data = client.get_playlist_tracks(id, market).await;
 
     if with_features {
-        let track_ids: Vec<String> = data
+        // episodes, local files and removed items have no audio features,
+        // so only catalog tracks are exported
+        let tracks: Vec<FullTrack> = data
             .iter()
-            .map(|playlist_track| playlist_track.track.id.clone())
+            .filter_map(|playlist_track| playlist_track.catalog_track().cloned())
             .collect();
+        report_skipped_items(&data);
+        let track_ids = track_ids(tracks.iter().map(FullTrack::catalog_id));
 
         let features = client.get_audio_features(track_ids).await;
 
         // merge data with features
-        let data_with_features: Vec<TrackWithFeatures> = data
-            .iter()
+        let data_with_features: Vec<TrackWithFeatures> = tracks
+            .into_iter()
             .zip(features)
-            .map(|(playlist_track, features)| TrackWithFeatures {
-                track: playlist_track.track.clone(),
-                features: features,
-            })
+            .map(|(track, features)| TrackWithFeatures { track, features })
             .collect();
 
         let json_resp = serde_json::to_string(&data_with_features)?;
@@ -47,17 +88,22 @@ pub async fn handle_fetch_playlist(id: &str, with_features: bool, env: Config) -
     return Ok(());
 }
 
-pub async fn handle_fetch_album(id: &str, with_features: bool, env: Config) -> Result<()> {
+pub async fn handle_fetch_album(
+    id: &AlbumId,
+    market: Option<Market>,
+    with_features: bool,
+    env: Config,
+) -> Result<()> {
     let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
-    let data = client.get_album_tracks(id).await;
+    let data = client.get_album_tracks(id, market).await;
 
     if with_features {
-        let track_ids: Vec<String> = data.iter().map(|track| track.id.clone()).collect();
+        let track_ids = track_ids(data.iter().map(SimplifiedTrack::catalog_id));
 
         let features = client.get_audio_features(track_ids).await;
 
         // merge data with features
-        let data_with_features: Vec<TrackWithFeatures> = data
+        let data_with_features: Vec<TrackWithFeatures<SimplifiedTrack>> = data
             .iter()
             .zip(features)
             .map(|(track, features)| TrackWithFeatures {
@@ -79,17 +125,18 @@ pub async fn handle_fetch_album(id: &str, with_features: bool, env: Config) -> R
 pub async fn handle_search_song(
     title: &str,
     artist: &str,
+    market: Option<Market>,
     with_features: bool,
     env: Config,
 ) -> Result<()> {
     let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
-    let data = match client.search_track(title, artist).await {
+    let data = match client.search_track(title, artist, market).await {
         Some(track) => track,
         None => panic!("Track not found"),
     };
 
     if with_features {
-        let track_ids: Vec<String> = vec![data.id.clone()];
+        let track_ids = track_ids(vec![data.catalog_id()]);
 
         let features = match client.get_audio_features(track_ids).await.into_iter().nth(0) {
             Some(feat) => feat,
@@ -120,7 +167,7 @@ pub async fn handle_get_user_playlists(with_features: bool, env: Config) -> Resu
 
     if with_features {
         let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
-        let track_ids: Vec<String> = tracks.iter().map(|track| track.id.clone()).collect();
+        let track_ids = track_ids(tracks.iter().map(FullTrack::catalog_id));
 
         let features = client.get_audio_features(track_ids).await;
 
@@ -144,15 +191,19 @@ pub async fn handle_get_user_playlists(with_features: bool, env: Config) -> Resu
     return Ok(());
 }
 
-pub async fn handle_get_user_albums(with_features: bool, env: Config) -> Result<()> {
+pub async fn handle_get_user_albums(
+    released_after: Option<ReleaseDate>,
+    with_features: bool,
+    env: Config,
+) -> Result<()> {
     let mut user_data = super::user::UserData::new(&env.client_id, &env.client_secret).await;
-    user_data.get_albums_track().await;
+    user_data.get_albums_track(released_after).await;
 
     let tracks = user_data.songs;
 
     if with_features {
         let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
-        let track_ids: Vec<String> = tracks.iter().map(|track| track.id.clone()).collect();
+        let track_ids = track_ids(tracks.iter().map(FullTrack::catalog_id));
 
         let features = client.get_audio_features(track_ids).await;
 
@@ -184,7 +235,7 @@ pub async fn handle_get_liked_songs(with_features: bool, env: Config) -> Result<
 
     if with_features {
         let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
-        let track_ids: Vec<String> = tracks.iter().map(|track| track.id.clone()).collect();
+        let track_ids = track_ids(tracks.iter().map(FullTrack::catalog_id));
 
         let features = client.get_audio_features(track_ids).await;
 
@@ -212,14 +263,14 @@ pub async fn handle_get_all_data(with_features: bool, env: Config) -> Result<()>
     let mut user_data = super::user::UserData::new(&env.client_id, &env.client_secret).await;
 
     user_data.get_playlists_track().await;
-    user_data.get_albums_track().await;
+    user_data.get_albums_track(None).await;
     user_data.get_liked_songs().await;
 
     let tracks = user_data.songs;
 
     if with_features {
         let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
-        let track_ids: Vec<String> = tracks.iter().map(|track| track.id.clone()).collect();
+        let track_ids = track_ids(tracks.iter().map(FullTrack::catalog_id));
 
         let features = client.get_audio_features(track_ids).await;
 
@@ -243,3 +294,88 @@ pub async fn handle_get_all_data(with_features: bool, env: Config) -> Result<()>
 
     return Ok(());
 }
+
+pub async fn handle_get_recently_played(
+    after: Option<u64>,
+    before: Option<u64>,
+    with_features: bool,
+    env: Config,
+) -> Result<()> {
+    let user_data = super::user::UserData::new(&env.client_id, &env.client_secret).await;
+    let cursor = match (after, before) {
+        (Some(after), _) => Some(PlayedCursor::After(after)),
+        (None, Some(before)) => Some(PlayedCursor::Before(before)),
+        (None, None) => None,
+    };
+    let history = user_data.get_recently_played(cursor).await;
+
+    if with_features {
+        let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
+        let track_ids = track_ids(history.iter().map(|played| played.track.catalog_id()));
+
+        let features = client.get_audio_features(track_ids).await;
+
+        // merge data with features
+        let data_with_features: Vec<PlayWithFeatures> = history
+            .into_iter()
+            .zip(features)
+            .map(|(played, features)| PlayWithFeatures {
+                played_at: played.played_at,
+                track: TrackWithFeatures {
+                    track: played.track,
+                    features,
+                },
+            })
+            .collect();
+
+        let json_resp = serde_json::to_string(&data_with_features)?;
+        std::io::stdout().write_all(json_resp.as_bytes()).unwrap();
+        return Ok(());
+    }
+
+    let json_resp = serde_json::to_string(&history)?;
+    std::io::stdout().write_all(json_resp.as_bytes()).unwrap();
+    Ok(())
+}
+
+pub async fn handle_get_top_artists(time_range: Option<TimeRange>, env: Config) -> Result<()> {
+    let user_data = super::user::UserData::new(&env.client_id, &env.client_secret).await;
+    let artists = user_data.get_top_artists(time_range).await;
+
+    let json_resp = serde_json::to_string(&artists)?;
+    std::io::stdout().write_all(json_resp.as_bytes()).unwrap();
+    Ok(())
+}
+
+pub async fn handle_get_top_tracks(
+    time_range: Option<TimeRange>,
+    with_features: bool,
+    env: Config,
+) -> Result<()> {
+    let mut user_data = super::user::UserData::new(&env.client_id, &env.client_secret).await;
+    user_data.get_top_tracks(time_range).await;
+
+    let tracks = user_data.songs;
+
+    if with_features {
+        let client = RSpotify::new(env.client_id, env.client_secret, None, None).await;
+        let track_ids = track_ids(tracks.iter().map(FullTrack::catalog_id));
+
+        let features = client.get_audio_features(track_ids).await;
+
+        // merge data with features
+        let data_with_features: Vec<TrackWithFeatures> = tracks
+            .into_iter()
+            .zip(features)
+            .map(|(track, features)| TrackWithFeatures { track, features })
+            .collect();
+
+        let json_resp = serde_json::to_string(&data_with_features)?;
+        std::io::stdout().write_all(json_resp.as_bytes()).unwrap();
+        return Ok(());
+    }
+
+    let json_resp = serde_json::to_string(&tracks)?;
+    std::io::stdout().write_all(json_resp.as_bytes()).unwrap();
+    Ok(())
+}
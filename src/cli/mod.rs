@@ -1,6 +1,10 @@
 pub mod handler;
 pub mod user;
 
+use rspotify_sdk::id::{AlbumId, PlaylistId};
+use rspotify_sdk::params::history::TimeRange;
+use rspotify_sdk::params::market::Market;
+use rspotify_sdk::response::spotify_types::ReleaseDate;
 use structopt::StructOpt;
 
 /// CLI application for getting data from spotify api
@@ -9,8 +13,12 @@ use structopt::StructOpt;
 pub enum RSpotifyCli {
     /// Get all tracks from a playlist
     GetPlaylistTracks {
-        /// playlist id
-        id: String,
+        /// playlist id, uri or url
+        id: PlaylistId,
+
+        /// country code to relink tracks for, or from_token for the user's country
+        #[structopt(long = "market")]
+        market: Option<Market>,
 
         /// adds the tracks features to the response
         #[structopt(long = "with-features")]
@@ -19,8 +27,12 @@ pub enum RSpotifyCli {
 
     /// Get all tracks from a album
     GetAlbumTracks {
-        /// album id
-        id: String,
+        /// album id, uri or url
+        id: AlbumId,
+
+        /// country code to relink tracks for, or from_token for the user's country
+        #[structopt(long = "market")]
+        market: Option<Market>,
 
         /// adds the tracks features to the response
         #[structopt(long = "with-features")]
@@ -37,6 +49,10 @@ pub enum RSpotifyCli {
         #[structopt(long = "artist")]
         artist: String,
 
+        /// country code to relink tracks for, or from_token for the user's country
+        #[structopt(long = "market")]
+        market: Option<Market>,
+
         /// adds the tracks features to the response
         #[structopt(long = "with-features")]
         with_features: bool,
@@ -51,6 +67,10 @@ pub enum RSpotifyCli {
 
     /// Get all user albums
     GetMyAlbums {
+        /// only include albums released entirely after this date (YYYY, YYYY-MM or YYYY-MM-DD)
+        #[structopt(long = "released-after")]
+        released_after: Option<ReleaseDate>,
+
         /// adds the tracks features to the response
         #[structopt(long = "with-features")]
         with_features: bool,
@@ -69,4 +89,37 @@ pub enum RSpotifyCli {
         #[structopt(long = "with-features")]
         with_features: bool,
     },
+
+    /// Get the user's recently played tracks
+    GetMyRecentlyPlayed {
+        /// only tracks played after this unix timestamp in milliseconds
+        #[structopt(long = "after", conflicts_with = "before")]
+        after: Option<u64>,
+
+        /// only tracks played before this unix timestamp in milliseconds
+        #[structopt(long = "before")]
+        before: Option<u64>,
+
+        /// adds the tracks features to the response
+        #[structopt(long = "with-features")]
+        with_features: bool,
+    },
+
+    /// Get the user's top artists
+    GetMyTopArtists {
+        /// short_term, medium_term or long_term
+        #[structopt(long = "time-range")]
+        time_range: Option<TimeRange>,
+    },
+
+    /// Get the user's top tracks
+    GetMyTopTracks {
+        /// short_term, medium_term or long_term
+        #[structopt(long = "time-range")]
+        time_range: Option<TimeRange>,
+
+        /// adds the tracks features to the response
+        #[structopt(long = "with-features")]
+        with_features: bool,
+    },
 }
@@ -1,8 +1,12 @@
-use rspotify_sdk::response::spotify_types::Track;
-use rspotify_sdk::RSpotify;
+use rspotify_sdk::id::TrackId;
+use rspotify_sdk::id::{AlbumId, PlaylistId};
+use rspotify_sdk::params::history::{PlayedCursor, TimeRange};
+use rspotify_sdk::response::history::PlayHistory;
+use rspotify_sdk::response::spotify_types::{FullArtist, FullTrack, ReleaseDate};
+use rspotify_sdk::{scopes, RSpotify};
 
 pub struct UserData {
-    pub songs: Vec<Track>,
+    pub songs: Vec<FullTrack>,
     client: RSpotify,
 }
 
@@ -12,7 +16,15 @@ impl UserData {
             client_id.to_owned(),
             client_secret.to_owned(),
             Some("user"),
-            Some("playlist-read-private user-library-read"),
+            Some(
+                &[
+                    scopes::PLAYLIST_READ_PRIVATE,
+                    scopes::USER_LIBRARY_READ,
+                    scopes::USER_READ_RECENTLY_PLAYED,
+                    scopes::USER_TOP_READ,
+                ]
+                .join(" "),
+            ),
         )
         .await;
 
@@ -30,39 +42,83 @@ impl UserData {
 
         for playlist in playlists {
             // get track in playlist
-            let tracks = self.client.get_playlist_tracks(&playlist.id).await;
-            let mut tracks: Vec<Track> = tracks
+            let playlist_id =
+                PlaylistId::from_id(&playlist.id).expect("spotify returned an invalid playlist id");
+            let tracks = self.client.get_playlist_tracks(&playlist_id, None).await;
+            let mut tracks: Vec<FullTrack> = tracks
                 .into_iter()
-                .map(|playlist_track| playlist_track.track.clone())
+                .filter_map(|playlist_track| playlist_track.catalog_track().cloned())
                 .collect();
 
             self.songs.append(&mut tracks);
         }
     }
 
-    pub async fn get_albums_track(&mut self) {
+    pub async fn get_albums_track(&mut self, released_after: Option<ReleaseDate>) {
         // get all playlist
-        let albums = self.client.get_user_albums().await;
+        let albums = self.client.get_user_albums(None).await;
+        let albums = albums.into_iter().filter(|album| match &released_after {
+            Some(date) => album.album.release_date.is_after(date),
+            None => true,
+        });
 
+        let mut track_ids: Vec<TrackId> = Vec::new();
         for album in albums {
-            // get track in album
-            let tracks = self.client.get_album_tracks(&album.album.id).await;
-            let mut tracks: Vec<Track> = tracks.into_iter().map(|track| track.clone()).collect();
+            // saved albums carry their first page of tracks, so only page through longer ones
+            let tracks = match album.album.tracks.next {
+                None => album.album.tracks.items,
+                Some(_) => {
+                    let album_id = AlbumId::from_id(&album.album.id)
+                        .expect("spotify returned an invalid album id");
+                    self.client.get_album_tracks(&album_id, None).await
+                }
+            };
 
-            self.songs.append(&mut tracks);
+            track_ids.extend(
+                tracks
+                    .iter()
+                    .filter_map(|track| track.catalog_id())
+                    .map(|id| TrackId::from_id(id).expect("spotify returned an invalid track id")),
+            );
         }
+
+        // album listings only carry simplified tracks, so fetch the full ones
+        // for every album at once, 50 per request
+        let mut tracks: Vec<FullTrack> = self
+            .client
+            .get_tracks(track_ids, None)
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+
+        self.songs.append(&mut tracks);
     }
 
     pub async fn get_liked_songs(&mut self) {
         // get all playlist
-        let saved_tracks = self.client.get_user_liked_songs().await;
+        let saved_tracks = self.client.get_user_liked_songs(None).await;
 
         // get track in playlist
-        let mut tracks: Vec<Track> = saved_tracks
+        let mut tracks: Vec<FullTrack> = saved_tracks
             .into_iter()
             .map(|saved_track| saved_track.track.clone())
             .collect();
 
         self.songs.append(&mut tracks);
     }
+
+    pub async fn get_top_tracks(&mut self, time_range: Option<TimeRange>) {
+        let mut tracks = self.client.get_top_tracks(time_range).await;
+
+        self.songs.append(&mut tracks);
+    }
+
+    pub async fn get_top_artists(&self, time_range: Option<TimeRange>) -> Vec<FullArtist> {
+        self.client.get_top_artists(time_range).await
+    }
+
+    pub async fn get_recently_played(&self, cursor: Option<PlayedCursor>) -> Vec<PlayHistory> {
+        self.client.get_recently_played(cursor).await
+    }
 }
@@ -0,0 +1,470 @@
+use percent_encoding::percent_decode_str;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use url::Url;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdError {
+    /// The id itself is malformed, e.g. not 22 base62 characters.
+    InvalidId(String),
+    /// A URI or URL pointed at a different kind of object.
+    WrongKind {
+        expected: &'static str,
+        found: String,
+    },
+    /// Not a recognisable id, `spotify:` URI or open.spotify.com URL.
+    InvalidFormat(String),
+}
+
+impl fmt::Display for IdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdError::InvalidId(id) => write!(f, "invalid spotify id {}", id),
+            IdError::WrongKind { expected, found } => {
+                write!(f, "expected a {} but got a {}", expected, found)
+            }
+            IdError::InvalidFormat(value) => {
+                write!(f, "{} is not a spotify id, uri or url", value)
+            }
+        }
+    }
+}
+
+impl Error for IdError {}
+
+/// Behaviour shared by every typed id.
+pub trait Id {
+    /// The object type as it appears in URIs and URLs, e.g. `track`.
+    const KIND: &'static str;
+
+    fn id(&self) -> &str;
+
+    fn uri(&self) -> String {
+        format!("spotify:{}:{}", Self::KIND, self.id())
+    }
+
+    fn url(&self) -> String {
+        format!("https://open.spotify.com/{}/{}", Self::KIND, self.id())
+    }
+}
+
+fn validate_base62(id: &str) -> Result<(), IdError> {
+    match id.len() == 22 && id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        true => Ok(()),
+        false => Err(IdError::InvalidId(id.to_owned())),
+    }
+}
+
+/// User ids are account names, so they are not base62.
+fn validate_user(id: &str) -> Result<(), IdError> {
+    let invalid = |c: char| c.is_whitespace() || ":/?#".contains(c);
+    match !id.is_empty() && !id.contains(invalid) {
+        true => Ok(()),
+        false => Err(IdError::InvalidId(id.to_owned())),
+    }
+}
+
+/// Pulls the id out of `spotify:<kind>:<id>`, also accepting the legacy
+/// `spotify:user:<user>:playlist:<id>` form for playlists.
+fn id_from_uri(uri: &str, kind: &'static str) -> Result<String, IdError> {
+    let parts: Vec<&str> = uri.split(':').collect();
+    let (found, id) = match parts.as_slice() {
+        ["spotify", "user", _, "playlist", id] => ("playlist", *id),
+        ["spotify", found, id] => (*found, *id),
+        _ => return Err(IdError::InvalidFormat(uri.to_owned())),
+    };
+
+    match found == kind {
+        true => Ok(id.to_owned()),
+        false => Err(IdError::WrongKind {
+            expected: kind,
+            found: found.to_owned(),
+        }),
+    }
+}
+
+/// Pulls the id out of an open.spotify.com link, ignoring locale prefixes
+/// such as `intl-de`, embed links and query strings like `?si=`.
+fn id_from_url(url: &str, kind: &'static str) -> Result<String, IdError> {
+    let invalid = || IdError::InvalidFormat(url.to_owned());
+    let parsed = Url::parse(url).map_err(|_| invalid())?;
+
+    match parsed.host_str() {
+        Some("open.spotify.com") | Some("play.spotify.com") => (),
+        _ => return Err(invalid()),
+    }
+
+    let segments: Vec<String> = parsed
+        .path_segments()
+        .ok_or_else(invalid)?
+        .filter(|segment| !segment.is_empty())
+        .filter(|segment| !segment.starts_with("intl-") && *segment != "embed")
+        .map(|segment| {
+            percent_decode_str(segment)
+                .decode_utf8()
+                .map(|segment| segment.into_owned())
+                .map_err(|_| invalid())
+        })
+        .collect::<Result<_, _>>()?;
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+
+    let (found, id) = match segments.as_slice() {
+        ["user", _, "playlist", id] => ("playlist", *id),
+        [found, id] => (*found, *id),
+        _ => return Err(invalid()),
+    };
+
+    match found == kind {
+        true => Ok(id.to_owned()),
+        false => Err(IdError::WrongKind {
+            expected: kind,
+            found: found.to_owned(),
+        }),
+    }
+}
+
+macro_rules! spotify_id {
+    ($name:ident, $kind:expr, $validate:ident) => {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Parses a bare id.
+            pub fn from_id(id: &str) -> Result<Self, IdError> {
+                $validate(id)?;
+                Ok($name(id.to_owned()))
+            }
+
+            /// Parses a `spotify:` URI.
+            pub fn from_uri(uri: &str) -> Result<Self, IdError> {
+                $name::from_id(&id_from_uri(uri, $kind)?)
+            }
+
+            /// Parses an open.spotify.com URL.
+            pub fn from_url(url: &str) -> Result<Self, IdError> {
+                $name::from_id(&id_from_url(url, $kind)?)
+            }
+        }
+
+        impl Id for $name {
+            const KIND: &'static str = $kind;
+
+            fn id(&self) -> &str {
+                &self.0
+            }
+        }
+
+        /// Accepts a bare id, a `spotify:` URI or an open.spotify.com URL.
+        impl FromStr for $name {
+            type Err = IdError;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                let value = value.trim();
+                if value.starts_with("spotify:") {
+                    $name::from_uri(value)
+                } else if value.starts_with("http://") || value.starts_with("https://") {
+                    $name::from_url(value)
+                } else {
+                    $name::from_id(value)
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+spotify_id!(TrackId, "track", validate_base62);
+spotify_id!(AlbumId, "album", validate_base62);
+spotify_id!(ArtistId, "artist", validate_base62);
+spotify_id!(PlaylistId, "playlist", validate_base62);
+spotify_id!(UserId, "user", validate_user);
+spotify_id!(ShowId, "show", validate_base62);
+spotify_id!(EpisodeId, "episode", validate_base62);
+spotify_id!(AudiobookId, "audiobook", validate_base62);
+spotify_id!(ChapterId, "chapter", validate_base62);
+
+/// A track or episode, the items that can be queued or added to playlists.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlayableId {
+    Track(TrackId),
+    Episode(EpisodeId),
+}
+
+impl PlayableId {
+    pub fn uri(&self) -> String {
+        match self {
+            PlayableId::Track(id) => id.uri(),
+            PlayableId::Episode(id) => id.uri(),
+        }
+    }
+}
+
+impl From<TrackId> for PlayableId {
+    fn from(id: TrackId) -> Self {
+        PlayableId::Track(id)
+    }
+}
+
+impl From<EpisodeId> for PlayableId {
+    fn from(id: EpisodeId) -> Self {
+        PlayableId::Episode(id)
+    }
+}
+
+/// Accepts a `spotify:` URI or an open.spotify.com URL. Bare ids are
+/// rejected because they don't say whether they are a track or an episode.
+impl FromStr for PlayableId {
+    type Err = IdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let found = match parse_kind::<TrackId>(value)? {
+            Ok(id) => return Ok(PlayableId::Track(id)),
+            Err(found) => found,
+        };
+
+        match found.as_str() {
+            EpisodeId::KIND => value.parse().map(PlayableId::Episode),
+            _ => Err(IdError::WrongKind {
+                expected: "track or episode",
+                found,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for PlayableId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.uri())
+    }
+}
+
+/// An album, artist, playlist or show that playback can be started from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContextId {
+    Album(AlbumId),
+    Artist(ArtistId),
+    Playlist(PlaylistId),
+    Show(ShowId),
+}
+
+impl ContextId {
+    pub fn uri(&self) -> String {
+        match self {
+            ContextId::Album(id) => id.uri(),
+            ContextId::Artist(id) => id.uri(),
+            ContextId::Playlist(id) => id.uri(),
+            ContextId::Show(id) => id.uri(),
+        }
+    }
+}
+
+impl From<AlbumId> for ContextId {
+    fn from(id: AlbumId) -> Self {
+        ContextId::Album(id)
+    }
+}
+
+impl From<ArtistId> for ContextId {
+    fn from(id: ArtistId) -> Self {
+        ContextId::Artist(id)
+    }
+}
+
+impl From<PlaylistId> for ContextId {
+    fn from(id: PlaylistId) -> Self {
+        ContextId::Playlist(id)
+    }
+}
+
+impl From<ShowId> for ContextId {
+    fn from(id: ShowId) -> Self {
+        ContextId::Show(id)
+    }
+}
+
+/// Accepts a `spotify:` URI or an open.spotify.com URL.
+impl FromStr for ContextId {
+    type Err = IdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let found = match parse_kind::<AlbumId>(value)? {
+            Ok(id) => return Ok(ContextId::Album(id)),
+            Err(found) => found,
+        };
+
+        match found.as_str() {
+            ArtistId::KIND => value.parse().map(ContextId::Artist),
+            PlaylistId::KIND => value.parse().map(ContextId::Playlist),
+            ShowId::KIND => value.parse().map(ContextId::Show),
+            _ => Err(IdError::WrongKind {
+                expected: "album, artist, playlist or show",
+                found,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for ContextId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.uri())
+    }
+}
+
+/// Parses a URI or URL as `T`, handing back the kind it actually names when
+/// that is a different kind of object.
+fn parse_kind<T: Id + FromStr<Err = IdError>>(value: &str) -> Result<Result<T, String>, IdError> {
+    let value = value.trim();
+    let is_link = value.starts_with("spotify:")
+        || value.starts_with("http://")
+        || value.starts_with("https://");
+    if !is_link {
+        return Err(IdError::InvalidFormat(value.to_owned()));
+    }
+
+    match value.parse::<T>() {
+        Ok(id) => Ok(Ok(id)),
+        Err(IdError::WrongKind { found, .. }) => Ok(Err(found)),
+        Err(err) => Err(err),
+    }
+}
+
+/// Joins ids for a batch request's `ids=` parameter.
+pub(crate) fn join_ids<T: Id>(ids: &[T]) -> String {
+    ids.iter().map(|id| id.id()).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACK: &str = "4uLU6hMCjMI75M1A2tKUQC";
+    const PLAYLIST: &str = "37i9dQZF1DXcBWIGoYBM5M";
+
+    #[test]
+    fn parses_bare_id() {
+        let id: TrackId = TRACK.parse().unwrap();
+        assert_eq!(id.id(), TRACK);
+        assert_eq!(id.uri(), format!("spotify:track:{}", TRACK));
+        assert_eq!(
+            id.url(),
+            format!("https://open.spotify.com/track/{}", TRACK)
+        );
+    }
+
+    #[test]
+    fn parses_uri() {
+        let id: TrackId = format!("spotify:track:{}", TRACK).parse().unwrap();
+        assert_eq!(id.id(), TRACK);
+    }
+
+    #[test]
+    fn parses_legacy_playlist_uri() {
+        let uri = format!("spotify:user:someone:playlist:{}", PLAYLIST);
+        let id: PlaylistId = uri.parse().unwrap();
+        assert_eq!(id.id(), PLAYLIST);
+    }
+
+    #[test]
+    fn parses_url_with_prefixes_and_query() {
+        let urls = [
+            format!("https://open.spotify.com/playlist/{}", PLAYLIST),
+            format!("https://open.spotify.com/intl-de/playlist/{}", PLAYLIST),
+            format!("https://open.spotify.com/embed/playlist/{}", PLAYLIST),
+            format!("https://open.spotify.com/playlist/{}?si=abc123", PLAYLIST),
+            format!(
+                "https://open.spotify.com/user/someone/playlist/{}",
+                PLAYLIST
+            ),
+        ];
+        for url in urls.iter() {
+            let id: PlaylistId = url.parse().unwrap();
+            assert_eq!(id.id(), PLAYLIST, "{}", url);
+        }
+    }
+
+    #[test]
+    fn percent_decodes_url_segments() {
+        let id: UserId = "https://open.spotify.com/user/j%C3%B6rg".parse().unwrap();
+        assert_eq!(id.id(), "jörg");
+        assert_eq!(id.uri(), "spotify:user:jörg");
+
+        let err = "https://open.spotify.com/user/some%20one"
+            .parse::<UserId>()
+            .unwrap_err();
+        assert_eq!(err, IdError::InvalidId("some one".to_owned()));
+    }
+
+    #[test]
+    fn rejects_wrong_kind() {
+        let err = format!("spotify:album:{}", TRACK)
+            .parse::<TrackId>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            IdError::WrongKind {
+                expected: "track",
+                found: "album".to_owned(),
+            }
+        );
+
+        let url = format!("https://open.spotify.com/album/{}", TRACK);
+        assert!(matches!(
+            url.parse::<TrackId>(),
+            Err(IdError::WrongKind { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_wrong_host() {
+        let url = format!("https://example.com/track/{}", TRACK);
+        assert!(matches!(
+            url.parse::<TrackId>(),
+            Err(IdError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn parses_playable_and_context_ids() {
+        let track: PlayableId = format!("spotify:track:{}", TRACK).parse().unwrap();
+        assert_eq!(track, PlayableId::Track(TrackId::from_id(TRACK).unwrap()));
+
+        let url = format!("https://open.spotify.com/episode/{}", TRACK);
+        let episode: PlayableId = url.parse().unwrap();
+        assert_eq!(episode.uri(), format!("spotify:episode:{}", TRACK));
+
+        assert!(matches!(
+            TRACK.parse::<PlayableId>(),
+            Err(IdError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            format!("spotify:album:{}", TRACK).parse::<PlayableId>(),
+            Err(IdError::WrongKind { .. })
+        ));
+
+        let legacy = format!("spotify:user:someone:playlist:{}", PLAYLIST);
+        let context: ContextId = legacy.parse().unwrap();
+        assert_eq!(context.uri(), format!("spotify:playlist:{}", PLAYLIST));
+    }
+
+    #[test]
+    fn rejects_bad_base62() {
+        for id in [
+            "tooshort",
+            "4uLU6hMCjMI75M1A2tKUQ!",
+            "4uLU6hMCjMI75M1A2tKUQCX",
+        ]
+        .iter()
+        {
+            assert_eq!(
+                id.parse::<TrackId>(),
+                Err(IdError::InvalidId((*id).to_owned()))
+            );
+        }
+    }
+}
@@ -1,24 +1,97 @@
+pub mod id;
+pub mod params;
 pub mod response;
+pub mod scopes;
 mod utils;
 
+use crate::id::{
+    AlbumId, ArtistId, AudiobookId, ChapterId, EpisodeId, Id, PlayableId, PlaylistId, ShowId,
+    TrackId, UserId,
+};
+use crate::params::fields::FieldsFilter;
+use crate::params::history::{PlayedCursor, TimeRange};
+use crate::params::market::{Country, Market};
+use crate::params::player::PlayOptions;
+use crate::params::playlist::{AdditionalType, PlaylistDetails, RemoveItem};
+use crate::params::recommendations::{RecommendationsError, RecommendationsOptions};
 use crate::response::album::UserAlbum;
+use crate::response::audio_analysis::AudioAnalysis;
 use crate::response::audio_features::AudioFeatures;
+use crate::response::audiobook::{Audiobook, Chapter, SimplifiedChapter};
 use crate::response::authorization::{ClientAuthorizeResponse, UserAuthorizeResponse};
-use crate::response::playlist::{PlaylistTrack, UserPlaylist};
-use crate::response::spotify_types::Track;
+use crate::response::browse::{Category, FeaturedPlaylists};
+use crate::response::episode::SavedEpisode;
+use crate::response::history::PlayHistory;
+use crate::response::player::{CurrentlyPlaying, Device, PlaybackState, Queue, RepeatState};
+use crate::response::playlist::{Playlist, PlaylistTrack, SnapshotResponse, UserPlaylist};
+use crate::response::recommendations::Recommendations;
+use crate::response::show::SavedShow;
+use crate::response::spotify_types::{
+    Episode, FullAlbum, FullArtist, FullTrack, Image, Show, SimplifiedAlbum, SimplifiedEpisode,
+    SimplifiedTrack,
+};
 use crate::response::track::SavedTrack;
+use crate::response::user::{CurrentUser, User};
+use crate::response::CollectionResponse;
 use base64::encode;
 use log::{info, warn};
-use reqwest::header;
-use utils::requests;
+use reqwest::{header, Error, Method};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+use utils::cache::TtlCache;
+use utils::requests::{self, FollowKind, LibraryKind};
 
 const SPOTIFY_AUTH_URL: &'static str = "https://accounts.spotify.com/api/token";
+const REFERENCE_DATA_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A raw request failed, or was refused because its URL is not on the API.
+#[derive(Debug)]
+pub enum RawRequestError {
+    /// An absolute URL outside `https://api.spotify.com/v1`. Raw requests
+    /// carry the bearer token, so they are only sent to the API itself.
+    ForeignUrl(String),
+    Request(Error),
+}
+
+impl fmt::Display for RawRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RawRequestError::ForeignUrl(url) => {
+                write!(f, "refusing to send a raw request to {}", url)
+            }
+            RawRequestError::Request(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for RawRequestError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RawRequestError::ForeignUrl(_) => None,
+            RawRequestError::Request(error) => Some(error),
+        }
+    }
+}
+
+impl From<Error> for RawRequestError {
+    fn from(error: Error) -> Self {
+        RawRequestError::Request(error)
+    }
+}
 
 pub struct RSpotify {
     client_id: String,
     client_secret: String,
     token: Option<String>,
     request: Option<requests::SpotifyRequest>,
+    current_user: Mutex<Option<CurrentUser>>,
+    markets: TtlCache<Vec<Market>>,
+    genre_seeds: TtlCache<Vec<String>>,
 }
 
 impl RSpotify {
@@ -33,6 +106,9 @@ impl RSpotify {
             client_secret,
             token: None,
             request: None,
+            current_user: Mutex::new(None),
+            markets: TtlCache::new(REFERENCE_DATA_TTL),
+            genre_seeds: TtlCache::new(REFERENCE_DATA_TTL),
         };
 
         match auth_type {
@@ -153,15 +229,30 @@ impl RSpotify {
         }
     }
 
-    pub async fn search_track(&self, title: &str, artist: &str) -> Option<Track> {
+    pub async fn search_track(
+        &self,
+        title: &str,
+        artist: &str,
+        market: Option<Market>,
+    ) -> Option<FullTrack> {
         let request = self.request.as_ref().unwrap();
-        let data = request.make_search_request(title, artist).await.unwrap();
+        let data = request
+            .make_search_request(title, artist, market)
+            .await
+            .unwrap();
         data.tracks.items.into_iter().nth(0)
     }
 
-    pub async fn get_playlist_tracks(&self, id: &str) -> Vec<PlaylistTrack> {
+    pub async fn get_playlist_tracks(
+        &self,
+        id: &PlaylistId,
+        market: Option<Market>,
+    ) -> Vec<PlaylistTrack> {
         let request = self.request.as_ref().unwrap();
-        let data = request.make_playlist_request(id, None).await.unwrap();
+        let data = request
+            .make_playlist_request(id.id(), market, None)
+            .await
+            .unwrap();
 
         let mut next = data.next;
 
@@ -169,7 +260,7 @@ impl RSpotify {
 
         while next.is_some() {
             let data = request
-                .make_playlist_request(id, next.as_ref())
+                .make_playlist_request(id.id(), market, next.as_ref())
                 .await
                 .unwrap();
 
@@ -182,15 +273,88 @@ impl RSpotify {
         return songs;
     }
 
-    pub async fn get_album_tracks(&self, id: &str) -> Vec<Track> {
+    /// Fetches a complete playlist with the first page of its items.
+    pub async fn get_playlist(
+        &self,
+        id: &PlaylistId,
+        market: Option<Market>,
+        additional_types: Option<&[AdditionalType]>,
+    ) -> Playlist {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_full_playlist_request(id.id(), None, market, additional_types)
+            .await
+            .unwrap()
+    }
+
+    /// Fetches a playlist projected to `fields`, deserialized into a type (or
+    /// `serde_json::Value`) matching only the requested fields.
+    pub async fn get_playlist_fields<T: for<'de> Deserialize<'de>>(
+        &self,
+        id: &PlaylistId,
+        fields: &FieldsFilter,
+        market: Option<Market>,
+        additional_types: Option<&[AdditionalType]>,
+    ) -> T {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_full_playlist_request(id.id(), Some(fields), market, additional_types)
+            .await
+            .unwrap()
+    }
+
+    /// Fetches every item of a playlist, projected to `fields` of each item,
+    /// e.g. `track(id,name)`. Much cheaper than `get_playlist_tracks` on
+    /// large playlists.
+    pub async fn get_playlist_items_fields<T: for<'de> Deserialize<'de>>(
+        &self,
+        id: &PlaylistId,
+        fields: &FieldsFilter,
+        market: Option<Market>,
+    ) -> Vec<T> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_playlist_items_fields_request::<T>(id.id(), fields, market, None)
+            .await
+            .unwrap();
+        let mut next = data.next;
+
+        let mut items = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_playlist_items_fields_request::<T>(id.id(), fields, market, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut page = data.items;
+            items.append(&mut page);
+        }
+
+        items
+    }
+
+    pub async fn get_album_tracks(
+        &self,
+        id: &AlbumId,
+        market: Option<Market>,
+    ) -> Vec<SimplifiedTrack> {
         let request = self.request.as_ref().unwrap();
-        let data = request.make_album_request(id, None).await.unwrap();
+        let data = request
+            .make_album_request(id.id(), market, None)
+            .await
+            .unwrap();
         let mut next = data.next;
 
         let mut songs = data.items;
 
         while next.is_some() {
-            let data = request.make_album_request(id, next.as_ref()).await.unwrap();
+            let data = request
+                .make_album_request(id.id(), market, next.as_ref())
+                .await
+                .unwrap();
 
             next = data.next;
 
@@ -201,7 +365,86 @@ impl RSpotify {
         return songs;
     }
 
-    pub async fn get_audio_features(&self, track_ids: Vec<String>) -> Vec<AudioFeatures> {
+    /// Fetches a track, or `None` when spotify has no track with that id.
+    pub async fn get_track(&self, id: &TrackId, market: Option<Market>) -> Option<FullTrack> {
+        let request = self.request.as_ref().unwrap();
+        request.make_track_request(id.id(), market).await.unwrap()
+    }
+
+    /// Fetches tracks in chunks of 50, keeping the order of `track_ids`.
+    /// Unknown ids come back as `None` in their original position.
+    pub async fn get_tracks(
+        &self,
+        track_ids: Vec<TrackId>,
+        market: Option<Market>,
+    ) -> Vec<Option<FullTrack>> {
+        let request = self.request.as_ref().unwrap();
+        let mut tracks: Vec<Option<FullTrack>> = Vec::new();
+
+        let track_chunks = track_ids.chunks(50);
+
+        for chunk in track_chunks {
+            let data = request.make_tracks_request(chunk, market).await.unwrap();
+            let mut items = data.tracks;
+            tracks.append(&mut items);
+        }
+
+        tracks
+    }
+
+    /// Maps each requested track to the track that plays in `market`, which
+    /// differs when spotify relinks it. Unavailable tracks map to `None`.
+    pub async fn get_playable_ids(
+        &self,
+        track_ids: Vec<TrackId>,
+        market: Market,
+    ) -> HashMap<TrackId, Option<TrackId>> {
+        let tracks = self.get_tracks(track_ids.clone(), Some(market)).await;
+
+        track_ids
+            .into_iter()
+            .zip(tracks)
+            .map(|(requested, track)| {
+                let playable = track
+                    .filter(|track| track.is_playable != Some(false))
+                    .and_then(|track| track.id)
+                    .and_then(|id| TrackId::from_id(&id).ok());
+                (requested, playable)
+            })
+            .collect()
+    }
+
+    /// Fetches an album, or `None` when spotify has no album with that id.
+    pub async fn get_album(&self, id: &AlbumId, market: Option<Market>) -> Option<FullAlbum> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_single_album_request(id.id(), market)
+            .await
+            .unwrap()
+    }
+
+    /// Fetches albums in chunks of 20, keeping the order of `album_ids`.
+    /// Unknown ids come back as `None` in their original position.
+    pub async fn get_albums(
+        &self,
+        album_ids: Vec<AlbumId>,
+        market: Option<Market>,
+    ) -> Vec<Option<FullAlbum>> {
+        let request = self.request.as_ref().unwrap();
+        let mut albums: Vec<Option<FullAlbum>> = Vec::new();
+
+        let album_chunks = album_ids.chunks(20);
+
+        for chunk in album_chunks {
+            let data = request.make_albums_request(chunk, market).await.unwrap();
+            let mut items = data.albums;
+            albums.append(&mut items);
+        }
+
+        albums
+    }
+
+    pub async fn get_audio_features(&self, track_ids: Vec<TrackId>) -> Vec<AudioFeatures> {
         let request = self.request.as_ref().unwrap();
         let mut audio_features: Vec<AudioFeatures> = Vec::new();
 
@@ -216,6 +459,335 @@ impl RSpotify {
         return audio_features;
     }
 
+    /// Fetches a track's audio analysis, or `None` when spotify has none for it.
+    pub async fn get_audio_analysis(&self, track_id: &TrackId) -> Option<AudioAnalysis> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_audio_analysis_request(track_id.id())
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_recommendations(
+        &self,
+        options: &RecommendationsOptions,
+    ) -> Result<Recommendations, RecommendationsError> {
+        options.validate()?;
+
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_recommendations_request(options).await?;
+        Ok(data)
+    }
+
+    /// Genres usable as recommendation seeds. Cached for a day.
+    pub async fn available_genre_seeds(&self) -> Vec<String> {
+        if let Some(genres) = self.genre_seeds.get() {
+            return genres;
+        }
+
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_genre_seeds_request().await.unwrap();
+
+        self.genre_seeds.set(data.genres.clone());
+        data.genres
+    }
+
+    /// Markets where Spotify is available. Cached for a day.
+    pub async fn available_markets(&self) -> Vec<Market> {
+        if let Some(markets) = self.markets.get() {
+            return markets;
+        }
+
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_markets_request().await.unwrap();
+
+        self.markets.set(data.markets.clone());
+        data.markets
+    }
+
+    pub async fn get_new_releases(&self, country: Option<Country>) -> Vec<SimplifiedAlbum> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_new_releases_request(country, None)
+            .await
+            .unwrap();
+        let mut next = data.albums.next;
+
+        let mut albums = data.albums.items;
+
+        while next.is_some() {
+            let data = request
+                .make_new_releases_request(country, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.albums.next;
+
+            let mut items = data.albums.items;
+            albums.append(&mut items);
+        }
+
+        albums
+    }
+
+    pub async fn get_featured_playlists(
+        &self,
+        country: Option<Country>,
+        locale: Option<&str>,
+    ) -> FeaturedPlaylists {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_featured_playlists_request(country, locale, None)
+            .await
+            .unwrap();
+        let message = data.message;
+        let mut next = data.playlists.next;
+
+        let mut playlists = data.playlists.items;
+
+        while next.is_some() {
+            let data = request
+                .make_featured_playlists_request(country, locale, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.playlists.next;
+
+            let mut items = data.playlists.items;
+            playlists.append(&mut items);
+        }
+
+        FeaturedPlaylists { message, playlists }
+    }
+
+    pub async fn get_categories(
+        &self,
+        country: Option<Country>,
+        locale: Option<&str>,
+    ) -> Vec<Category> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_categories_request(country, locale, None)
+            .await
+            .unwrap();
+        let mut next = data.categories.next;
+
+        let mut categories = data.categories.items;
+
+        while next.is_some() {
+            let data = request
+                .make_categories_request(country, locale, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.categories.next;
+
+            let mut items = data.categories.items;
+            categories.append(&mut items);
+        }
+
+        categories
+    }
+
+    /// Fetches a browse category, or `None` when there is no category with
+    /// that id.
+    pub async fn get_category(
+        &self,
+        id: &str,
+        country: Option<Country>,
+        locale: Option<&str>,
+    ) -> Option<Category> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_category_request(id, country, locale)
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_category_playlists(
+        &self,
+        id: &str,
+        country: Option<Country>,
+    ) -> Vec<UserPlaylist> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_category_playlists_request(id, country, None)
+            .await
+            .unwrap();
+        let mut next = data.playlists.next;
+
+        let mut playlists = data.playlists.items;
+
+        while next.is_some() {
+            let data = request
+                .make_category_playlists_request(id, country, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.playlists.next;
+
+            let mut items = data.playlists.items;
+            playlists.append(&mut items);
+        }
+
+        playlists
+    }
+
+    /// Fetches a show, or `None` when spotify has none with that id.
+    pub async fn get_show(&self, id: &ShowId, market: Option<Market>) -> Option<Show> {
+        let request = self.request.as_ref().unwrap();
+        request.make_show_request(id.id(), market).await.unwrap()
+    }
+
+    pub async fn get_show_episodes(
+        &self,
+        id: &ShowId,
+        market: Option<Market>,
+    ) -> Vec<SimplifiedEpisode> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_show_episodes_request(id.id(), market, None)
+            .await
+            .unwrap();
+        let mut next = data.next;
+
+        let mut episodes = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_show_episodes_request(id.id(), market, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            episodes.append(&mut items);
+        }
+
+        episodes
+    }
+
+    /// Fetches an episode, or `None` when spotify has none with that id.
+    pub async fn get_episode(&self, id: &EpisodeId, market: Option<Market>) -> Option<Episode> {
+        let request = self.request.as_ref().unwrap();
+        request.make_episode_request(id.id(), market).await.unwrap()
+    }
+
+    /// Fetches episodes in chunks of 50, keeping the order of `episode_ids`.
+    /// Unknown ids come back as `None` in their original position.
+    pub async fn get_episodes(
+        &self,
+        episode_ids: Vec<EpisodeId>,
+        market: Option<Market>,
+    ) -> Vec<Option<Episode>> {
+        let request = self.request.as_ref().unwrap();
+        let mut episodes: Vec<Option<Episode>> = Vec::new();
+
+        let episode_chunks = episode_ids.chunks(50);
+
+        for chunk in episode_chunks {
+            let data = request.make_episodes_request(chunk, market).await.unwrap();
+            let mut items = data.episodes;
+            episodes.append(&mut items);
+        }
+
+        episodes
+    }
+
+    /// Fetches an audiobook, or `None` when spotify has none with that id.
+    pub async fn get_audiobook(
+        &self,
+        id: &AudiobookId,
+        market: Option<Market>,
+    ) -> Option<Audiobook> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_audiobook_request(id.id(), market)
+            .await
+            .unwrap()
+    }
+
+    /// Fetches audiobooks in chunks of 50, keeping the order of `audiobook_ids`.
+    /// Unknown ids come back as `None` in their original position.
+    pub async fn get_audiobooks(
+        &self,
+        audiobook_ids: Vec<AudiobookId>,
+        market: Option<Market>,
+    ) -> Vec<Option<Audiobook>> {
+        let request = self.request.as_ref().unwrap();
+        let mut audiobooks: Vec<Option<Audiobook>> = Vec::new();
+
+        let audiobook_chunks = audiobook_ids.chunks(50);
+
+        for chunk in audiobook_chunks {
+            let data = request
+                .make_audiobooks_request(chunk, market)
+                .await
+                .unwrap();
+            let mut items = data.audiobooks;
+            audiobooks.append(&mut items);
+        }
+
+        audiobooks
+    }
+
+    pub async fn get_audiobook_chapters(
+        &self,
+        id: &AudiobookId,
+        market: Option<Market>,
+    ) -> Vec<SimplifiedChapter> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_audiobook_chapters_request(id.id(), market, None)
+            .await
+            .unwrap();
+        let mut next = data.next;
+
+        let mut chapters = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_audiobook_chapters_request(id.id(), market, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            chapters.append(&mut items);
+        }
+
+        chapters
+    }
+
+    /// Fetches a chapter, or `None` when spotify has none with that id.
+    pub async fn get_chapter(&self, id: &ChapterId, market: Option<Market>) -> Option<Chapter> {
+        let request = self.request.as_ref().unwrap();
+        request.make_chapter_request(id.id(), market).await.unwrap()
+    }
+
+    /// Fetches chapters in chunks of 50, keeping the order of `chapter_ids`.
+    /// Unknown ids come back as `None` in their original position.
+    pub async fn get_chapters(
+        &self,
+        chapter_ids: Vec<ChapterId>,
+        market: Option<Market>,
+    ) -> Vec<Option<Chapter>> {
+        let request = self.request.as_ref().unwrap();
+        let mut chapters: Vec<Option<Chapter>> = Vec::new();
+
+        let chapter_chunks = chapter_ids.chunks(50);
+
+        for chunk in chapter_chunks {
+            let data = request.make_chapters_request(chunk, market).await.unwrap();
+            let mut items = data.chapters;
+            chapters.append(&mut items);
+        }
+
+        chapters
+    }
+
     pub async fn get_user_playlists(&self) -> Vec<UserPlaylist> {
         let request = self.request.as_ref().unwrap();
         let data = request.make_user_playlist_request(None).await.unwrap();
@@ -238,16 +810,52 @@ impl RSpotify {
         return songs;
     }
 
-    pub async fn get_user_albums(&self) -> Vec<UserAlbum> {
+    pub async fn get_user_playlists_of(&self, user_id: &UserId) -> Vec<UserPlaylist> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_other_user_playlist_request(user_id.id(), None)
+            .await
+            .unwrap();
+        let mut next = data.next;
+
+        let mut playlists = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_other_user_playlist_request(user_id.id(), next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            playlists.append(&mut items);
+        }
+
+        playlists
+    }
+
+    /// The current user's playlists, leaving out ones they only follow.
+    pub async fn get_own_playlists(&self) -> Vec<UserPlaylist> {
+        let me = self.current_user().await;
+        let playlists = self.get_user_playlists().await;
+
+        playlists
+            .into_iter()
+            .filter(|playlist| playlist.owner.id == me.id)
+            .collect()
+    }
+
+    pub async fn get_user_albums(&self, market: Option<Market>) -> Vec<UserAlbum> {
         let request = self.request.as_ref().unwrap();
-        let data = request.make_user_album_request(None).await.unwrap();
+        let data = request.make_user_album_request(market, None).await.unwrap();
         let mut next = data.next;
 
         let mut songs = data.items;
 
         while next.is_some() {
             let data = request
-                .make_user_album_request(next.as_ref())
+                .make_user_album_request(market, next.as_ref())
                 .await
                 .unwrap();
 
@@ -260,16 +868,19 @@ impl RSpotify {
         return songs;
     }
 
-    pub async fn get_user_liked_songs(&self) -> Vec<SavedTrack> {
+    pub async fn get_user_liked_songs(&self, market: Option<Market>) -> Vec<SavedTrack> {
         let request = self.request.as_ref().unwrap();
-        let data = request.make_user_saved_song_request(None).await.unwrap();
+        let data = request
+            .make_user_saved_song_request(market, None)
+            .await
+            .unwrap();
         let mut next = data.next;
 
         let mut songs = data.items;
 
         while next.is_some() {
             let data = request
-                .make_user_saved_song_request(next.as_ref())
+                .make_user_saved_song_request(market, next.as_ref())
                 .await
                 .unwrap();
 
@@ -281,4 +892,723 @@ impl RSpotify {
 
         return songs;
     }
+
+    /// Fetches the user's recently played tracks, optionally only those played
+    /// after or before a point in time. Reads after a point in time return a
+    /// single page of at most 50 tracks.
+    pub async fn get_recently_played(&self, cursor: Option<PlayedCursor>) -> Vec<PlayHistory> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_recently_played_request(cursor, None)
+            .await
+            .unwrap();
+        let mut next = PlayedCursor::next_page(cursor, data.next);
+
+        let mut history = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_recently_played_request(cursor, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            history.append(&mut items);
+        }
+
+        history
+    }
+
+    pub async fn get_top_artists(&self, time_range: Option<TimeRange>) -> Vec<FullArtist> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_top_artists_request(time_range, None)
+            .await
+            .unwrap();
+        let mut next = data.next;
+
+        let mut artists = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_top_artists_request(time_range, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            artists.append(&mut items);
+        }
+
+        artists
+    }
+
+    pub async fn get_top_tracks(&self, time_range: Option<TimeRange>) -> Vec<FullTrack> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_top_tracks_request(time_range, None)
+            .await
+            .unwrap();
+        let mut next = data.next;
+
+        let mut tracks = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_top_tracks_request(time_range, next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            tracks.append(&mut items);
+        }
+
+        tracks
+    }
+
+    pub async fn get_user_shows(&self) -> Vec<SavedShow> {
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_user_show_request(None).await.unwrap();
+        let mut next = data.next;
+
+        let mut shows = data.items;
+
+        while next.is_some() {
+            let data = request.make_user_show_request(next.as_ref()).await.unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            shows.append(&mut items);
+        }
+
+        shows
+    }
+
+    pub async fn get_user_episodes(&self) -> Vec<SavedEpisode> {
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_user_episode_request(None).await.unwrap();
+        let mut next = data.next;
+
+        let mut episodes = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_user_episode_request(next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            episodes.append(&mut items);
+        }
+
+        episodes
+    }
+
+    pub async fn get_user_audiobooks(&self) -> Vec<Audiobook> {
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_user_audiobook_request(None).await.unwrap();
+        let mut next = data.next;
+
+        let mut audiobooks = data.items;
+
+        while next.is_some() {
+            let data = request
+                .make_user_audiobook_request(next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.next;
+
+            let mut items = data.items;
+            audiobooks.append(&mut items);
+        }
+
+        audiobooks
+    }
+
+    pub async fn save_tracks(&self, track_ids: Vec<TrackId>) {
+        self.save_to_library(LibraryKind::Tracks, track_ids).await
+    }
+
+    pub async fn remove_saved_tracks(&self, track_ids: Vec<TrackId>) {
+        self.remove_from_library(LibraryKind::Tracks, track_ids)
+            .await
+    }
+
+    /// Returns whether each track is saved, in the order of `track_ids`.
+    pub async fn check_saved_tracks(&self, track_ids: Vec<TrackId>) -> Vec<bool> {
+        self.check_library(LibraryKind::Tracks, track_ids).await
+    }
+
+    pub async fn save_albums(&self, album_ids: Vec<AlbumId>) {
+        self.save_to_library(LibraryKind::Albums, album_ids).await
+    }
+
+    pub async fn remove_saved_albums(&self, album_ids: Vec<AlbumId>) {
+        self.remove_from_library(LibraryKind::Albums, album_ids)
+            .await
+    }
+
+    /// Returns whether each album is saved, in the order of `album_ids`.
+    pub async fn check_saved_albums(&self, album_ids: Vec<AlbumId>) -> Vec<bool> {
+        self.check_library(LibraryKind::Albums, album_ids).await
+    }
+
+    pub async fn save_shows(&self, show_ids: Vec<ShowId>) {
+        self.save_to_library(LibraryKind::Shows, show_ids).await
+    }
+
+    pub async fn remove_saved_shows(&self, show_ids: Vec<ShowId>) {
+        self.remove_from_library(LibraryKind::Shows, show_ids).await
+    }
+
+    /// Returns whether each show is saved, in the order of `show_ids`.
+    pub async fn check_saved_shows(&self, show_ids: Vec<ShowId>) -> Vec<bool> {
+        self.check_library(LibraryKind::Shows, show_ids).await
+    }
+
+    pub async fn save_episodes(&self, episode_ids: Vec<EpisodeId>) {
+        self.save_to_library(LibraryKind::Episodes, episode_ids)
+            .await
+    }
+
+    pub async fn remove_saved_episodes(&self, episode_ids: Vec<EpisodeId>) {
+        self.remove_from_library(LibraryKind::Episodes, episode_ids)
+            .await
+    }
+
+    /// Returns whether each episode is saved, in the order of `episode_ids`.
+    pub async fn check_saved_episodes(&self, episode_ids: Vec<EpisodeId>) -> Vec<bool> {
+        self.check_library(LibraryKind::Episodes, episode_ids).await
+    }
+
+    pub async fn save_audiobooks(&self, audiobook_ids: Vec<AudiobookId>) {
+        self.save_to_library(LibraryKind::Audiobooks, audiobook_ids)
+            .await
+    }
+
+    pub async fn remove_saved_audiobooks(&self, audiobook_ids: Vec<AudiobookId>) {
+        self.remove_from_library(LibraryKind::Audiobooks, audiobook_ids)
+            .await
+    }
+
+    /// Returns whether each audiobook is saved, in the order of `audiobook_ids`.
+    pub async fn check_saved_audiobooks(&self, audiobook_ids: Vec<AudiobookId>) -> Vec<bool> {
+        self.check_library(LibraryKind::Audiobooks, audiobook_ids)
+            .await
+    }
+
+    async fn save_to_library<T: Id>(&self, kind: LibraryKind, ids: Vec<T>) {
+        let request = self.request.as_ref().unwrap();
+
+        for chunk in ids.chunks(kind.chunk_size()) {
+            request
+                .make_save_library_request(kind, chunk)
+                .await
+                .unwrap();
+        }
+    }
+
+    async fn remove_from_library<T: Id>(&self, kind: LibraryKind, ids: Vec<T>) {
+        let request = self.request.as_ref().unwrap();
+
+        for chunk in ids.chunks(kind.chunk_size()) {
+            request
+                .make_remove_library_request(kind, chunk)
+                .await
+                .unwrap();
+        }
+    }
+
+    async fn check_library<T: Id>(&self, kind: LibraryKind, ids: Vec<T>) -> Vec<bool> {
+        let request = self.request.as_ref().unwrap();
+        let mut saved: Vec<bool> = Vec::new();
+
+        for chunk in ids.chunks(kind.chunk_size()) {
+            let mut items = request
+                .make_check_library_request(kind, chunk)
+                .await
+                .unwrap();
+            saved.append(&mut items);
+        }
+
+        saved
+    }
+
+    /// Returns `None` when nothing is playing on any of the user's devices.
+    pub async fn get_playback_state(&self, market: Option<Market>) -> Option<PlaybackState> {
+        let request = self.request.as_ref().unwrap();
+        request.make_playback_state_request(market).await.unwrap()
+    }
+
+    /// Returns `None` when nothing is playing on any of the user's devices.
+    pub async fn get_currently_playing(&self, market: Option<Market>) -> Option<CurrentlyPlaying> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_currently_playing_request(market)
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_available_devices(&self) -> Vec<Device> {
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_devices_request().await.unwrap();
+        data.devices
+    }
+
+    pub async fn transfer_playback(&self, device_id: &str, play: Option<bool>) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_transfer_playback_request(device_id, play)
+            .await
+            .unwrap();
+    }
+
+    pub async fn start_playback(&self, device_id: Option<&str>, options: &PlayOptions) {
+        let request = self.request.as_ref().unwrap();
+        request.make_play_request(device_id, options).await.unwrap();
+    }
+
+    pub async fn pause_playback(&self, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request.make_pause_request(device_id).await.unwrap();
+    }
+
+    pub async fn next_track(&self, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request.make_next_request(device_id).await.unwrap();
+    }
+
+    pub async fn previous_track(&self, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request.make_previous_request(device_id).await.unwrap();
+    }
+
+    pub async fn seek(&self, position_ms: u64, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_seek_request(position_ms, device_id)
+            .await
+            .unwrap();
+    }
+
+    pub async fn set_repeat(&self, state: RepeatState, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request.make_repeat_request(state, device_id).await.unwrap();
+    }
+
+    pub async fn set_shuffle(&self, state: bool, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_shuffle_request(state, device_id)
+            .await
+            .unwrap();
+    }
+
+    pub async fn set_volume(&self, volume_percent: u8, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_volume_request(volume_percent.min(100), device_id)
+            .await
+            .unwrap();
+    }
+
+    pub async fn add_to_queue(&self, item: &PlayableId, device_id: Option<&str>) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_add_to_queue_request(item, device_id)
+            .await
+            .unwrap();
+    }
+
+    pub async fn get_queue(&self) -> Queue {
+        let request = self.request.as_ref().unwrap();
+        request.make_queue_request().await.unwrap()
+    }
+
+    pub async fn create_playlist(
+        &self,
+        user_id: &UserId,
+        details: &PlaylistDetails,
+    ) -> UserPlaylist {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_create_playlist_request(user_id.id(), details)
+            .await
+            .unwrap()
+    }
+
+    pub async fn change_playlist_details(
+        &self,
+        playlist_id: &PlaylistId,
+        details: &PlaylistDetails,
+    ) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_change_playlist_details_request(playlist_id.id(), details)
+            .await
+            .unwrap();
+    }
+
+    /// Adds items in chunks of 100, keeping their order. When `position` is
+    /// set, the items are inserted there as one block; otherwise they are appended.
+    /// Returns the snapshot id after the last chunk.
+    pub async fn add_items(
+        &self,
+        playlist_id: &PlaylistId,
+        items: Vec<PlayableId>,
+        position: Option<u32>,
+    ) -> String {
+        let request = self.request.as_ref().unwrap();
+        let mut snapshot_id = String::new();
+
+        for (index, chunk) in items.chunks(100).enumerate() {
+            let chunk_position = position.map(|position| position + (index * 100) as u32);
+            let data = request
+                .make_add_items_request(playlist_id.id(), chunk, chunk_position)
+                .await
+                .unwrap();
+            snapshot_id = data.snapshot_id;
+        }
+
+        snapshot_id
+    }
+
+    /// Removes items in chunks of 100. Positional removals are resolved against
+    /// `snapshot_id`, or against the current snapshot when removing more than
+    /// one chunk without one. Returns the snapshot id after the last chunk.
+    pub async fn remove_items(
+        &self,
+        playlist_id: &PlaylistId,
+        items: Vec<RemoveItem>,
+        snapshot_id: Option<&str>,
+    ) -> String {
+        let request = self.request.as_ref().unwrap();
+        let mut new_snapshot_id = String::new();
+
+        // later chunks would otherwise see positions shifted by earlier ones
+        let has_positions = items.iter().any(|item| item.positions.is_some());
+        let snapshot_id = match snapshot_id {
+            None if has_positions && items.len() > 100 => {
+                let fields = FieldsFilter::new().field("snapshot_id");
+                let data = request
+                    .make_full_playlist_request::<SnapshotResponse>(
+                        playlist_id.id(),
+                        Some(&fields),
+                        None,
+                        None,
+                    )
+                    .await
+                    .unwrap();
+                Some(data.snapshot_id)
+            }
+            snapshot_id => snapshot_id.map(str::to_owned),
+        };
+
+        for chunk in items.chunks(100) {
+            let data = request
+                .make_remove_items_request(playlist_id.id(), chunk, snapshot_id.as_deref())
+                .await
+                .unwrap();
+            new_snapshot_id = data.snapshot_id;
+        }
+
+        new_snapshot_id
+    }
+
+    /// Moves `range_length` items starting at `range_start` to just before
+    /// `insert_before`, returning the new snapshot id.
+    pub async fn reorder_items(
+        &self,
+        playlist_id: &PlaylistId,
+        range_start: u32,
+        range_length: u32,
+        insert_before: u32,
+        snapshot_id: Option<&str>,
+    ) -> String {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_reorder_items_request(
+                playlist_id.id(),
+                range_start,
+                range_length,
+                insert_before,
+                snapshot_id,
+            )
+            .await
+            .unwrap();
+        data.snapshot_id
+    }
+
+    /// Replaces every item in the playlist with `items`. Spotify only accepts
+    /// 100 items per replace, so anything beyond that is appended afterwards.
+    /// Returns the snapshot id after the last request.
+    pub async fn replace_items(&self, playlist_id: &PlaylistId, items: Vec<PlayableId>) -> String {
+        let request = self.request.as_ref().unwrap();
+        let split = items.len().min(100);
+        let (first, rest) = items.split_at(split);
+
+        let data = request
+            .make_replace_items_request(playlist_id.id(), first)
+            .await
+            .unwrap();
+
+        match rest.len() {
+            0 => data.snapshot_id,
+            _ => self.add_items(playlist_id, rest.to_vec(), None).await,
+        }
+    }
+
+    pub async fn get_playlist_cover_image(&self, playlist_id: &PlaylistId) -> Vec<Image> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_playlist_cover_image_request(playlist_id.id())
+            .await
+            .unwrap()
+    }
+
+    /// Uploads a JPEG as the playlist cover. Fails without making a request if
+    /// the image is not a JPEG or is over 256 KB once base64-encoded.
+    /// Failed uploads, e.g. ones spotify rejects, come back as `Other` errors.
+    pub async fn upload_playlist_cover_image(
+        &self,
+        playlist_id: &PlaylistId,
+        image: &[u8],
+    ) -> std::io::Result<()> {
+        let encoded = utils::encode_cover_image(image)?;
+
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_upload_playlist_cover_image_request(playlist_id.id(), encoded)
+            .await
+            .map_err(std::io::Error::other)
+    }
+
+    pub async fn upload_playlist_cover_image_from_path<P: AsRef<Path>>(
+        &self,
+        playlist_id: &PlaylistId,
+        path: P,
+    ) -> std::io::Result<()> {
+        let image = std::fs::read(path)?;
+        self.upload_playlist_cover_image(playlist_id, &image).await
+    }
+
+    pub async fn get_followed_artists(&self) -> Vec<FullArtist> {
+        let request = self.request.as_ref().unwrap();
+        let data = request.make_followed_artists_request(None).await.unwrap();
+        let mut next = data.artists.next;
+
+        let mut artists = data.artists.items;
+
+        while next.is_some() {
+            let data = request
+                .make_followed_artists_request(next.as_ref())
+                .await
+                .unwrap();
+
+            next = data.artists.next;
+
+            let mut items = data.artists.items;
+            artists.append(&mut items);
+        }
+
+        artists
+    }
+
+    pub async fn follow_artists(&self, artist_ids: Vec<ArtistId>) {
+        self.follow(FollowKind::Artist, artist_ids).await
+    }
+
+    pub async fn unfollow_artists(&self, artist_ids: Vec<ArtistId>) {
+        self.unfollow(FollowKind::Artist, artist_ids).await
+    }
+
+    /// Returns whether the user follows each artist, in the order of `artist_ids`.
+    pub async fn check_following_artists(&self, artist_ids: Vec<ArtistId>) -> Vec<bool> {
+        self.check_following(FollowKind::Artist, artist_ids).await
+    }
+
+    pub async fn follow_users(&self, user_ids: Vec<UserId>) {
+        self.follow(FollowKind::User, user_ids).await
+    }
+
+    pub async fn unfollow_users(&self, user_ids: Vec<UserId>) {
+        self.unfollow(FollowKind::User, user_ids).await
+    }
+
+    /// Returns whether the user follows each user, in the order of `user_ids`.
+    pub async fn check_following_users(&self, user_ids: Vec<UserId>) -> Vec<bool> {
+        self.check_following(FollowKind::User, user_ids).await
+    }
+
+    /// Follows a playlist, showing it on the user's profile when `public` is set.
+    pub async fn follow_playlist(&self, playlist_id: &PlaylistId, public: bool) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_follow_playlist_request(playlist_id.id(), public)
+            .await
+            .unwrap();
+    }
+
+    pub async fn unfollow_playlist(&self, playlist_id: &PlaylistId) {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_unfollow_playlist_request(playlist_id.id())
+            .await
+            .unwrap();
+    }
+
+    /// Returns whether each user follows the playlist, in the order of `user_ids`.
+    pub async fn check_users_follow_playlist(
+        &self,
+        playlist_id: &PlaylistId,
+        user_ids: Vec<UserId>,
+    ) -> Vec<bool> {
+        let request = self.request.as_ref().unwrap();
+        let mut following: Vec<bool> = Vec::new();
+
+        for chunk in user_ids.chunks(5) {
+            let mut items = request
+                .make_check_playlist_followers_request(playlist_id.id(), chunk)
+                .await
+                .unwrap();
+            following.append(&mut items);
+        }
+
+        following
+    }
+
+    async fn follow<T: Id>(&self, kind: FollowKind, ids: Vec<T>) {
+        let request = self.request.as_ref().unwrap();
+
+        for chunk in ids.chunks(50) {
+            request.make_follow_request(kind, chunk).await.unwrap();
+        }
+    }
+
+    async fn unfollow<T: Id>(&self, kind: FollowKind, ids: Vec<T>) {
+        let request = self.request.as_ref().unwrap();
+
+        for chunk in ids.chunks(50) {
+            request.make_unfollow_request(kind, chunk).await.unwrap();
+        }
+    }
+
+    async fn check_following<T: Id>(&self, kind: FollowKind, ids: Vec<T>) -> Vec<bool> {
+        let request = self.request.as_ref().unwrap();
+        let mut following: Vec<bool> = Vec::new();
+
+        for chunk in ids.chunks(50) {
+            let mut items = request
+                .make_check_following_request(kind, chunk)
+                .await
+                .unwrap();
+            following.append(&mut items);
+        }
+
+        following
+    }
+
+    /// The profile of the authorized user. It is fetched once and cached for
+    /// the lifetime of the client.
+    pub async fn current_user(&self) -> CurrentUser {
+        if let Some(user) = self.current_user.lock().unwrap().as_ref() {
+            return user.clone();
+        }
+
+        let request = self.request.as_ref().unwrap();
+        let user = request.make_current_user_request().await.unwrap();
+
+        *self.current_user.lock().unwrap() = Some(user.clone());
+        user
+    }
+
+    /// Fetches a user's public profile, or `None` when there is no such user.
+    pub async fn get_user(&self, user_id: &UserId) -> Option<User> {
+        let request = self.request.as_ref().unwrap();
+        request.make_user_request(user_id.id()).await.unwrap()
+    }
+
+    /// Sends a GET to any endpoint the SDK doesn't model yet and deserializes
+    /// the response, e.g. into a `serde_json::Value`. `path` is relative to
+    /// the API root, like `me/shows?limit=5`, or a full API URL such as a
+    /// `next` link. Any other absolute URL is refused with `ForeignUrl`.
+    pub async fn get_raw<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+    ) -> Result<T, RawRequestError> {
+        let request = self.request.as_ref().unwrap();
+        request.make_raw_request(Method::GET, path, None).await
+    }
+
+    /// Like `get_raw`, but sends `body` as JSON. Endpoints that answer
+    /// without a body give `None`.
+    pub async fn post_raw<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Option<T>, RawRequestError> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_optional_raw_request(Method::POST, path, body)
+            .await
+    }
+
+    pub async fn put_raw<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Option<T>, RawRequestError> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_optional_raw_request(Method::PUT, path, body)
+            .await
+    }
+
+    pub async fn delete_raw<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Option<T>, RawRequestError> {
+        let request = self.request.as_ref().unwrap();
+        request
+            .make_optional_raw_request(Method::DELETE, path, body)
+            .await
+    }
+
+    /// Collects every page of an endpoint that answers with a paging object
+    /// at the top level, e.g. `me/shows`.
+    pub async fn paginate_raw<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+    ) -> Result<Vec<T>, RawRequestError> {
+        let request = self.request.as_ref().unwrap();
+        let data = request
+            .make_raw_request::<CollectionResponse<T>>(Method::GET, path, None)
+            .await?;
+        let mut next = data.next;
+
+        let mut items = data.items;
+
+        while let Some(link) = next {
+            let data = request
+                .make_raw_request::<CollectionResponse<T>>(Method::GET, &link, None)
+                .await?;
+
+            next = data.next;
+
+            let mut page = data.items;
+            items.append(&mut page);
+        }
+
+        Ok(items)
+    }
 }
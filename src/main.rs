@@ -24,25 +24,37 @@ async fn main() -> Result<()> {
 
     // Handle args
     match args {
-        RSpotifyCli::GetPlaylistTracks { id, with_features } => {
-            cli::handler::handle_fetch_playlist(&id, with_features, env).await?;
+        RSpotifyCli::GetPlaylistTracks {
+            id,
+            market,
+            with_features,
+        } => {
+            cli::handler::handle_fetch_playlist(&id, market, with_features, env).await?;
         }
-        RSpotifyCli::GetAlbumTracks { id, with_features } => {
-            cli::handler::handle_fetch_album(&id, with_features, env).await?;
+        RSpotifyCli::GetAlbumTracks {
+            id,
+            market,
+            with_features,
+        } => {
+            cli::handler::handle_fetch_album(&id, market, with_features, env).await?;
         }
         RSpotifyCli::Search {
             title,
             artist,
+            market,
             with_features,
         } => {
-            cli::handler::handle_search_song(&title, &artist, with_features, env).await?;
+            cli::handler::handle_search_song(&title, &artist, market, with_features, env).await?;
         }
         // user data
         RSpotifyCli::GetMyPlaylists { with_features } => {
             cli::handler::handle_get_user_playlists(with_features, env).await?;
         }
-        RSpotifyCli::GetMyAlbums { with_features } => {
-            cli::handler::handle_get_user_albums(with_features, env).await?;
+        RSpotifyCli::GetMyAlbums {
+            released_after,
+            with_features,
+        } => {
+            cli::handler::handle_get_user_albums(released_after, with_features, env).await?;
         }
         RSpotifyCli::GetMyLikedSongs { with_features } => {
             cli::handler::handle_get_liked_songs(with_features, env).await?;
@@ -50,6 +62,23 @@ async fn main() -> Result<()> {
         RSpotifyCli::GetMyData { with_features } => {
             cli::handler::handle_get_all_data(with_features, env).await?;
         }
+        // listening history
+        RSpotifyCli::GetMyRecentlyPlayed {
+            after,
+            before,
+            with_features,
+        } => {
+            cli::handler::handle_get_recently_played(after, before, with_features, env).await?;
+        }
+        RSpotifyCli::GetMyTopArtists { time_range } => {
+            cli::handler::handle_get_top_artists(time_range, env).await?;
+        }
+        RSpotifyCli::GetMyTopTracks {
+            time_range,
+            with_features,
+        } => {
+            cli::handler::handle_get_top_tracks(time_range, with_features, env).await?;
+        }
     }
 
     Ok(())
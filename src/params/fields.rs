@@ -0,0 +1,44 @@
+use std::fmt;
+
+/// A `fields=` projection, limiting a response to the listed fields. Nesting
+/// `track` with `id` and `name` inside `items` renders as `items(track(id,name))`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldsFilter {
+    parts: Vec<String>,
+}
+
+impl FieldsFilter {
+    pub fn new() -> Self {
+        FieldsFilter::default()
+    }
+
+    /// Includes a field. Dotted paths such as `tracks.total` reach into nested
+    /// objects without listing their siblings.
+    pub fn field(mut self, name: &str) -> Self {
+        self.parts.push(name.to_owned());
+        self
+    }
+
+    /// Leaves a field out of an object whose other fields are all returned.
+    pub fn exclude(mut self, name: &str) -> Self {
+        self.parts.push(format!("!{}", name));
+        self
+    }
+
+    /// Includes only the given fields of a nested object, or of every object
+    /// in a nested array.
+    pub fn nested(mut self, name: &str, fields: FieldsFilter) -> Self {
+        self.parts.push(format!("{}({})", name, fields));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+}
+
+impl fmt::Display for FieldsFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.parts.join(","))
+    }
+}
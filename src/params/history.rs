@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+/// Over what period of listening the user's top items are calculated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeRange {
+    /// Roughly the last four weeks.
+    ShortTerm,
+    /// Roughly the last six months.
+    MediumTerm,
+    /// Several years of data.
+    LongTerm,
+}
+
+impl TimeRange {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeRange::ShortTerm => "short_term",
+            TimeRange::MediumTerm => "medium_term",
+            TimeRange::LongTerm => "long_term",
+        }
+    }
+}
+
+impl FromStr for TimeRange {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "short_term" => Ok(TimeRange::ShortTerm),
+            "medium_term" => Ok(TimeRange::MediumTerm),
+            "long_term" => Ok(TimeRange::LongTerm),
+            _ => Err(format!(
+                "invalid time range {}, expected short_term, medium_term or long_term",
+                value
+            )),
+        }
+    }
+}
+
+/// Reads listening history from one side of a unix timestamp in milliseconds.
+/// Spotify accepts only one of the two.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlayedCursor {
+    After(u64),
+    Before(u64),
+}
+
+impl PlayedCursor {
+    /// The link to follow after a page read with `cursor`. Spotify's `next`
+    /// link always pages further back with `before`, so an `After` read stops
+    /// at its first page instead of crossing its timestamp.
+    pub(crate) fn next_page(cursor: Option<PlayedCursor>, next: Option<String>) -> Option<String> {
+        match cursor {
+            Some(PlayedCursor::After(_)) => None,
+            Some(PlayedCursor::Before(_)) | None => next,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NEXT: &str = "https://api.spotify.com/v1/me/player/recently-played?before=1600000000000";
+
+    #[test]
+    fn after_reads_stop_at_the_first_page() {
+        let next = PlayedCursor::next_page(Some(PlayedCursor::After(1)), Some(NEXT.to_owned()));
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn before_reads_follow_next_links() {
+        for cursor in &[Some(PlayedCursor::Before(1)), None] {
+            let next = PlayedCursor::next_page(*cursor, Some(NEXT.to_owned()));
+            assert_eq!(next.as_deref(), Some(NEXT));
+            assert_eq!(PlayedCursor::next_page(*cursor, None), None);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// A market to resolve content availability and track relinking against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Market {
+    /// An ISO 3166-1 alpha-2 country code, always upper case.
+    Country([u8; 2]),
+    /// The country of the user the access token belongs to.
+    FromToken,
+}
+
+impl Market {
+    /// Parses a two letter country code in either case, e.g. `"gb"`.
+    pub fn country(code: &str) -> Option<Market> {
+        Country::new(code).map(Market::from)
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            // only ever built from ascii letters
+            Market::Country(code) => std::str::from_utf8(code).unwrap(),
+            Market::FromToken => "from_token",
+        }
+    }
+}
+
+impl FromStr for Market {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "from_token" => Ok(Market::FromToken),
+            _ => Market::country(value).ok_or_else(|| {
+                format!(
+                    "invalid market {}, expected a two letter country code or from_token",
+                    value
+                )
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Market {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for Market {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Market {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+/// An ISO 3166-1 alpha-2 country code, always upper case. Used where spotify
+/// wants a country rather than a market, such as the browse endpoints, which
+/// don't accept `from_token`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Country([u8; 2]);
+
+impl Country {
+    /// Parses a two letter country code in either case, e.g. `"gb"`.
+    pub fn new(code: &str) -> Option<Country> {
+        match code.as_bytes() {
+            [first, second] if first.is_ascii_alphabetic() && second.is_ascii_alphabetic() => {
+                Some(Country([
+                    first.to_ascii_uppercase(),
+                    second.to_ascii_uppercase(),
+                ]))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // only ever built from ascii letters
+        std::str::from_utf8(&self.0).unwrap()
+    }
+}
+
+impl From<Country> for Market {
+    fn from(country: Country) -> Self {
+        Market::Country(country.0)
+    }
+}
+
+impl FromStr for Country {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Country::new(value).ok_or_else(|| {
+            format!(
+                "invalid country {}, expected a two letter country code",
+                value
+            )
+        })
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
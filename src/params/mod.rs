@@ -0,0 +1,6 @@
+pub mod fields;
+pub mod history;
+pub mod market;
+pub mod player;
+pub mod playlist;
+pub mod recommendations;
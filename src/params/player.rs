@@ -0,0 +1,74 @@
+use crate::id::{ContextId, PlayableId};
+use serde_json::{json, Map, Value};
+
+/// Where in the context playback should start.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaybackOffset {
+    Position(u32),
+    Item(PlayableId),
+}
+
+/// What to play when starting or resuming playback. An empty set of options
+/// resumes whatever was playing before.
+#[derive(Debug, Clone, Default)]
+pub struct PlayOptions {
+    context: Option<ContextId>,
+    items: Option<Vec<PlayableId>>,
+    offset: Option<PlaybackOffset>,
+    position_ms: Option<u64>,
+}
+
+impl PlayOptions {
+    pub fn new() -> Self {
+        PlayOptions::default()
+    }
+
+    pub fn context(mut self, context: ContextId) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    pub fn items(mut self, items: Vec<PlayableId>) -> Self {
+        self.items = Some(items);
+        self
+    }
+
+    pub fn offset(mut self, offset: PlaybackOffset) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn position_ms(mut self, position_ms: u64) -> Self {
+        self.position_ms = Some(position_ms);
+        self
+    }
+
+    pub(crate) fn to_body(&self) -> Option<Value> {
+        let mut body = Map::new();
+
+        if let Some(context) = &self.context {
+            body.insert("context_uri".to_owned(), json!(context.uri()));
+        }
+        if let Some(items) = &self.items {
+            let uris: Vec<String> = items.iter().map(PlayableId::uri).collect();
+            body.insert("uris".to_owned(), json!(uris));
+        }
+        match &self.offset {
+            Some(PlaybackOffset::Position(position)) => {
+                body.insert("offset".to_owned(), json!({ "position": position }));
+            }
+            Some(PlaybackOffset::Item(item)) => {
+                body.insert("offset".to_owned(), json!({ "uri": item.uri() }));
+            }
+            None => (),
+        }
+        if let Some(position_ms) = self.position_ms {
+            body.insert("position_ms".to_owned(), json!(position_ms));
+        }
+
+        match body.len() {
+            0 => None,
+            _ => Some(Value::Object(body)),
+        }
+    }
+}
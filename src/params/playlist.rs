@@ -0,0 +1,105 @@
+use crate::id::PlayableId;
+use serde_json::{json, Map, Value};
+
+/// Name, visibility and description of a playlist. Fields left unset are not
+/// sent, so changing details only touches what was set here.
+#[derive(Debug, Clone, Default)]
+pub struct PlaylistDetails {
+    name: Option<String>,
+    public: Option<bool>,
+    collaborative: Option<bool>,
+    description: Option<String>,
+}
+
+impl PlaylistDetails {
+    pub fn new() -> Self {
+        PlaylistDetails::default()
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    /// Collaborative playlists must also be private.
+    pub fn collaborative(mut self, collaborative: bool) -> Self {
+        self.collaborative = Some(collaborative);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    pub(crate) fn to_body(&self) -> Value {
+        let mut body = Map::new();
+
+        if let Some(name) = &self.name {
+            body.insert("name".to_owned(), json!(name));
+        }
+        if let Some(public) = self.public {
+            body.insert("public".to_owned(), json!(public));
+        }
+        if let Some(collaborative) = self.collaborative {
+            body.insert("collaborative".to_owned(), json!(collaborative));
+        }
+        if let Some(description) = &self.description {
+            body.insert("description".to_owned(), json!(description));
+        }
+
+        Value::Object(body)
+    }
+}
+
+/// An item to remove from a playlist: every occurrence of `item`, or only
+/// the occurrences at the given positions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoveItem {
+    pub item: PlayableId,
+    pub positions: Option<Vec<u32>>,
+}
+
+impl RemoveItem {
+    pub fn all(item: PlayableId) -> Self {
+        RemoveItem {
+            item,
+            positions: None,
+        }
+    }
+
+    pub fn at(item: PlayableId, positions: Vec<u32>) -> Self {
+        RemoveItem {
+            item,
+            positions: Some(positions),
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> Value {
+        match &self.positions {
+            Some(positions) => json!({ "uri": self.item.uri(), "positions": positions }),
+            None => json!({ "uri": self.item.uri() }),
+        }
+    }
+}
+
+/// Item types a playlist request should return in full, besides tracks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdditionalType {
+    Track,
+    Episode,
+}
+
+impl AdditionalType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdditionalType::Track => "track",
+            AdditionalType::Episode => "episode",
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use super::market::Market;
+use crate::id::{join_ids, ArtistId, TrackId};
+use crate::response::audio_features::{Mode, PitchClass, TimeSignature};
+use std::error::Error;
+use std::fmt;
+use url::form_urlencoded;
+
+/// A track attribute that recommendations can be tuned on, carrying the value
+/// to use as a `min_`, `max_` or `target_` bound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tunable {
+    Acousticness(f64),
+    Danceability(f64),
+    DurationMs(u64),
+    Energy(f64),
+    Instrumentalness(f64),
+    Key(PitchClass),
+    Liveness(f64),
+    Loudness(f64),
+    Mode(Mode),
+    Popularity(u8),
+    Speechiness(f64),
+    Tempo(f64),
+    TimeSignature(TimeSignature),
+    Valence(f64),
+}
+
+impl Tunable {
+    fn name(&self) -> &'static str {
+        match self {
+            Tunable::Acousticness(_) => "acousticness",
+            Tunable::Danceability(_) => "danceability",
+            Tunable::DurationMs(_) => "duration_ms",
+            Tunable::Energy(_) => "energy",
+            Tunable::Instrumentalness(_) => "instrumentalness",
+            Tunable::Key(_) => "key",
+            Tunable::Liveness(_) => "liveness",
+            Tunable::Loudness(_) => "loudness",
+            Tunable::Mode(_) => "mode",
+            Tunable::Popularity(_) => "popularity",
+            Tunable::Speechiness(_) => "speechiness",
+            Tunable::Tempo(_) => "tempo",
+            Tunable::TimeSignature(_) => "time_signature",
+            Tunable::Valence(_) => "valence",
+        }
+    }
+
+    fn value(&self) -> String {
+        match *self {
+            Tunable::DurationMs(value) => value.to_string(),
+            Tunable::Key(pitch_class) => i32::from(pitch_class).to_string(),
+            Tunable::Mode(mode) => i32::from(mode).to_string(),
+            Tunable::Popularity(value) => value.to_string(),
+            Tunable::TimeSignature(time_signature) => time_signature.0.to_string(),
+            Tunable::Acousticness(value)
+            | Tunable::Danceability(value)
+            | Tunable::Energy(value)
+            | Tunable::Instrumentalness(value)
+            | Tunable::Liveness(value)
+            | Tunable::Loudness(value)
+            | Tunable::Speechiness(value)
+            | Tunable::Tempo(value)
+            | Tunable::Valence(value) => value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Bound {
+    Min,
+    Max,
+    Target,
+}
+
+impl Bound {
+    fn prefix(&self) -> &'static str {
+        match self {
+            Bound::Min => "min",
+            Bound::Max => "max",
+            Bound::Target => "target",
+        }
+    }
+}
+
+/// Seeds and tunable attributes for a `/recommendations` request.
+///
+/// Spotify accepts at most five seeds in total across artists, tracks and genres.
+#[derive(Debug, Clone, Default)]
+pub struct RecommendationsOptions {
+    seed_artists: Vec<ArtistId>,
+    seed_tracks: Vec<TrackId>,
+    seed_genres: Vec<String>,
+    limit: Option<u32>,
+    market: Option<Market>,
+    tunables: Vec<(Bound, Tunable)>,
+}
+
+impl RecommendationsOptions {
+    pub fn new() -> Self {
+        RecommendationsOptions::default()
+    }
+
+    pub fn seed_artist(mut self, id: &ArtistId) -> Self {
+        self.seed_artists.push(id.clone());
+        self
+    }
+
+    pub fn seed_track(mut self, id: &TrackId) -> Self {
+        self.seed_tracks.push(id.clone());
+        self
+    }
+
+    pub fn seed_genre(mut self, genre: &str) -> Self {
+        self.seed_genres.push(genre.to_owned());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn market(mut self, market: Market) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    pub fn min(mut self, tunable: Tunable) -> Self {
+        self.tunables.push((Bound::Min, tunable));
+        self
+    }
+
+    pub fn max(mut self, tunable: Tunable) -> Self {
+        self.tunables.push((Bound::Max, tunable));
+        self
+    }
+
+    pub fn target(mut self, tunable: Tunable) -> Self {
+        self.tunables.push((Bound::Target, tunable));
+        self
+    }
+
+    pub fn seed_count(&self) -> usize {
+        self.seed_artists.len() + self.seed_tracks.len() + self.seed_genres.len()
+    }
+
+    /// Checks that there are between one and five seeds.
+    pub fn validate(&self) -> Result<(), SeedCountError> {
+        match self.seed_count() {
+            1..=5 => Ok(()),
+            count => Err(SeedCountError { count }),
+        }
+    }
+
+    pub(crate) fn to_query(&self) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+
+        if !self.seed_artists.is_empty() {
+            query.append_pair("seed_artists", &join_ids(&self.seed_artists));
+        }
+        if !self.seed_tracks.is_empty() {
+            query.append_pair("seed_tracks", &join_ids(&self.seed_tracks));
+        }
+        if !self.seed_genres.is_empty() {
+            query.append_pair("seed_genres", &self.seed_genres.join(","));
+        }
+        if let Some(limit) = self.limit {
+            query.append_pair("limit", &limit.to_string());
+        }
+        if let Some(market) = &self.market {
+            query.append_pair("market", market.as_str());
+        }
+
+        for (bound, tunable) in &self.tunables {
+            let key = format!("{}_{}", bound.prefix(), tunable.name());
+            query.append_pair(&key, &tunable.value());
+        }
+
+        query.finish()
+    }
+}
+
+/// Recommendations were requested with no seeds or more than five.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeedCountError {
+    pub count: usize,
+}
+
+impl fmt::Display for SeedCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "recommendations need between 1 and 5 seeds, got {}",
+            self.count
+        )
+    }
+}
+
+impl Error for SeedCountError {}
+
+/// Recommendations could not be fetched, either because the options were
+/// rejected before sending or because the request failed.
+#[derive(Debug)]
+pub enum RecommendationsError {
+    SeedCount(SeedCountError),
+    Request(reqwest::Error),
+}
+
+impl fmt::Display for RecommendationsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecommendationsError::SeedCount(error) => write!(f, "{}", error),
+            RecommendationsError::Request(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for RecommendationsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RecommendationsError::SeedCount(error) => Some(error),
+            RecommendationsError::Request(error) => Some(error),
+        }
+    }
+}
+
+impl From<SeedCountError> for RecommendationsError {
+    fn from(error: SeedCountError) -> Self {
+        RecommendationsError::SeedCount(error)
+    }
+}
+
+impl From<reqwest::Error> for RecommendationsError {
+    fn from(error: reqwest::Error) -> Self {
+        RecommendationsError::Request(error)
+    }
+}
@@ -1,10 +1,31 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct UserAlbum {
-    pub added_at: String,
-    pub album: super::spotify_types::Album,
+    pub added_at: super::Timestamp,
+    pub album: super::spotify_types::FullAlbum,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl super::AddedAt for UserAlbum {
+    fn added_at(&self) -> Option<&super::Timestamp> {
+        Some(&self.added_at)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AlbumsResponse {
+    pub albums: Vec<Option<super::spotify_types::FullAlbum>>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-pub type AlbumTracksResponse = super::CollectionResponse<super::spotify_types::Track>;
+pub type AlbumTracksResponse = super::CollectionResponse<super::spotify_types::SimplifiedTrack>;
 pub type UserAlbumResponse = super::CollectionResponse<UserAlbum>;
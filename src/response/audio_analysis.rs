@@ -0,0 +1,123 @@
+use super::audio_features::{Mode, PitchClass, TimeSignature};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AnalysisMeta {
+    pub analyzer_version: String,
+    pub platform: String,
+    pub detailed_status: String,
+    pub status_code: i32,
+    pub timestamp: u64,
+    pub analysis_time: f64,
+    pub input_process: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AnalysisTrack {
+    pub num_samples: u64,
+    pub duration: f64,
+    pub sample_md5: Option<String>,
+    pub offset_seconds: f64,
+    pub window_seconds: f64,
+    pub analysis_sample_rate: u32,
+    pub analysis_channels: u32,
+    pub end_of_fade_in: f64,
+    pub start_of_fade_out: f64,
+    pub loudness: f64,
+    pub tempo: f64,
+    pub tempo_confidence: f64,
+    pub time_signature: TimeSignature,
+    pub time_signature_confidence: f64,
+    pub key: PitchClass,
+    pub key_confidence: f64,
+    pub mode: Mode,
+    pub mode_confidence: f64,
+    pub codestring: Option<String>,
+    pub code_version: Option<f64>,
+    pub echoprintstring: Option<String>,
+    pub echoprint_version: Option<f64>,
+    pub synchstring: Option<String>,
+    pub synch_version: Option<f64>,
+    pub rhythmstring: Option<String>,
+    pub rhythm_version: Option<f64>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A bar, beat or tatum: a span of time with the analyzer's confidence in it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TimeInterval {
+    pub start: f64,
+    pub duration: f64,
+    pub confidence: f64,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Section {
+    pub start: f64,
+    pub duration: f64,
+    pub confidence: f64,
+    pub loudness: f64,
+    pub tempo: f64,
+    pub tempo_confidence: f64,
+    pub key: PitchClass,
+    pub key_confidence: f64,
+    pub mode: Mode,
+    pub mode_confidence: f64,
+    pub time_signature: TimeSignature,
+    pub time_signature_confidence: f64,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Segment {
+    pub start: f64,
+    pub duration: f64,
+    pub confidence: f64,
+    pub loudness_start: f64,
+    pub loudness_max: f64,
+    pub loudness_max_time: f64,
+    pub loudness_end: Option<f64>,
+    /// Relative dominance of each of the 12 pitch classes, C through B.
+    pub pitches: Vec<f64>,
+    /// 12 unbounded values describing the segment's tone colour.
+    pub timbre: Vec<f64>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AudioAnalysis {
+    pub meta: AnalysisMeta,
+    pub track: AnalysisTrack,
+    pub bars: Vec<TimeInterval>,
+    pub beats: Vec<TimeInterval>,
+    pub sections: Vec<Section>,
+    pub segments: Vec<Segment>,
+    pub tatums: Vec<TimeInterval>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
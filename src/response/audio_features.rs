@@ -1,12 +1,16 @@
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::Duration;
 
 #[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AudioFeatures {
     pub danceability: f64,
     pub energy: f64,
-    pub key: u64,
+    pub key: PitchClass,
     pub loudness: f64,
-    pub mode: f64,
+    pub mode: Mode,
     pub speechiness: f64,
     pub acousticness: f64,
     pub instrumentalness: f64,
@@ -19,10 +23,312 @@ pub struct AudioFeatures {
     pub track_href: String,
     pub analysis_url: String,
     pub duration_ms: u64,
-    pub time_signature: u64,
+    pub time_signature: TimeSignature,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AudioFeatures {
+    pub fn musical_key(&self) -> MusicalKey {
+        MusicalKey {
+            pitch_class: self.key,
+            mode: self.mode,
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_millis(self.duration_ms)
+    }
+}
+
+/// A key in standard pitch class notation, serialized as spotify's raw
+/// `-1..=11` integer where `-1` means no key was detected.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "i32", into = "i32")]
+pub enum PitchClass {
+    Unknown,
+    C,
+    CSharp,
+    D,
+    DSharp,
+    E,
+    F,
+    FSharp,
+    G,
+    GSharp,
+    A,
+    ASharp,
+    B,
+}
+
+const PITCH_CLASSES: [PitchClass; 12] = [
+    PitchClass::C,
+    PitchClass::CSharp,
+    PitchClass::D,
+    PitchClass::DSharp,
+    PitchClass::E,
+    PitchClass::F,
+    PitchClass::FSharp,
+    PitchClass::G,
+    PitchClass::GSharp,
+    PitchClass::A,
+    PitchClass::ASharp,
+    PitchClass::B,
+];
+
+const PITCH_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+impl PitchClass {
+    /// The pitch class number `0..=11`, or `None` when unknown.
+    pub fn number(&self) -> Option<u8> {
+        PITCH_CLASSES
+            .iter()
+            .position(|pitch_class| pitch_class == self)
+            .map(|number| number as u8)
+    }
+
+    pub fn name(&self) -> Option<&'static str> {
+        self.number().map(|number| PITCH_NAMES[number as usize])
+    }
+}
+
+impl TryFrom<i32> for PitchClass {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            -1 => Ok(PitchClass::Unknown),
+            0..=11 => Ok(PITCH_CLASSES[value as usize]),
+            _ => Err(format!("invalid pitch class {}", value)),
+        }
+    }
+}
+
+impl From<PitchClass> for i32 {
+    fn from(pitch_class: PitchClass) -> Self {
+        pitch_class.number().map_or(-1, i32::from)
+    }
+}
+
+/// The modality of a track, serialized as spotify's raw `1` for major and
+/// `0` for minor. Audio analysis sections use `-1` when no mode was detected.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "i32", into = "i32")]
+pub enum Mode {
+    Unknown,
+    Minor,
+    Major,
+}
+
+impl Mode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mode::Unknown => "unknown",
+            Mode::Minor => "minor",
+            Mode::Major => "major",
+        }
+    }
+}
+
+impl TryFrom<i32> for Mode {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            -1 => Ok(Mode::Unknown),
+            0 => Ok(Mode::Minor),
+            1 => Ok(Mode::Major),
+            _ => Err(format!("invalid mode {}", value)),
+        }
+    }
+}
+
+impl From<Mode> for i32 {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Unknown => -1,
+            Mode::Minor => 0,
+            Mode::Major => 1,
+        }
+    }
+}
+
+/// The number of beats per bar, read as `<beats>/4`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct TimeSignature(pub u32);
+
+impl fmt::Display for TimeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/4", self.0)
+    }
+}
+
+/// A pitch class together with its mode, e.g. F# minor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MusicalKey {
+    pub pitch_class: PitchClass,
+    pub mode: Mode,
+}
+
+impl MusicalKey {
+    /// The key in musical notation, e.g. `F# minor`.
+    pub fn notation(&self) -> Option<String> {
+        let name = self.pitch_class.name()?;
+        match self.mode {
+            Mode::Unknown => None,
+            mode => Some(format!("{} {}", name, mode.as_str())),
+        }
+    }
+
+    /// The Camelot wheel code used by DJ software, e.g. `11A` for F# minor.
+    pub fn camelot(&self) -> Option<String> {
+        let position = self.wheel_position()?;
+        let letter = match self.mode {
+            Mode::Minor => "A",
+            _ => "B",
+        };
+
+        // the camelot wheel starts at B major / G# minor
+        Some(format!("{}{}", (position + 7) % 12 + 1, letter))
+    }
+
+    /// The Open Key notation code, e.g. `4m` for F# minor.
+    pub fn open_key(&self) -> Option<String> {
+        let position = self.wheel_position()?;
+        let letter = match self.mode {
+            Mode::Minor => "m",
+            _ => "d",
+        };
+
+        // the open key wheel starts at C major / A minor
+        Some(format!("{}{}", position + 1, letter))
+    }
+
+    /// Steps around the circle of fifths from C major, using the relative
+    /// major for minor keys.
+    fn wheel_position(&self) -> Option<u8> {
+        let number = self.pitch_class.number()?;
+        let major = match self.mode {
+            Mode::Unknown => return None,
+            Mode::Minor => (number + 3) % 12,
+            Mode::Major => number,
+        };
+
+        Some(major * 7 % 12)
+    }
+}
+
+impl fmt::Display for MusicalKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.notation() {
+            Some(notation) => write!(f, "{}", notation),
+            None => write!(f, "unknown"),
+        }
+    }
 }
 
 #[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct AudioFeaturesResponse {
     pub audio_features: Vec<AudioFeatures>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // pitch class order, C to B
+    const MAJOR_CAMELOT: [&str; 12] = [
+        "8B", "3B", "10B", "5B", "12B", "7B", "2B", "9B", "4B", "11B", "6B", "1B",
+    ];
+    const MINOR_CAMELOT: [&str; 12] = [
+        "5A", "12A", "7A", "2A", "9A", "4A", "11A", "6A", "1A", "8A", "3A", "10A",
+    ];
+    const MAJOR_OPEN_KEY: [&str; 12] = [
+        "1d", "8d", "3d", "10d", "5d", "12d", "7d", "2d", "9d", "4d", "11d", "6d",
+    ];
+    const MINOR_OPEN_KEY: [&str; 12] = [
+        "10m", "5m", "12m", "7m", "2m", "9m", "4m", "11m", "6m", "1m", "8m", "3m",
+    ];
+
+    fn key(pitch_class: PitchClass, mode: Mode) -> MusicalKey {
+        MusicalKey { pitch_class, mode }
+    }
+
+    #[test]
+    fn names_major_keys() {
+        for (number, pitch_class) in PITCH_CLASSES.iter().enumerate() {
+            let major = key(*pitch_class, Mode::Major);
+            let name = PITCH_NAMES[number];
+            assert_eq!(major.notation(), Some(format!("{} major", name)));
+            assert_eq!(major.camelot().as_deref(), Some(MAJOR_CAMELOT[number]));
+            assert_eq!(major.open_key().as_deref(), Some(MAJOR_OPEN_KEY[number]));
+        }
+    }
+
+    #[test]
+    fn names_minor_keys() {
+        for (number, pitch_class) in PITCH_CLASSES.iter().enumerate() {
+            let minor = key(*pitch_class, Mode::Minor);
+            let name = PITCH_NAMES[number];
+            assert_eq!(minor.notation(), Some(format!("{} minor", name)));
+            assert_eq!(minor.camelot().as_deref(), Some(MINOR_CAMELOT[number]));
+            assert_eq!(minor.open_key().as_deref(), Some(MINOR_OPEN_KEY[number]));
+        }
+    }
+
+    #[test]
+    fn unknown_keys_have_no_name() {
+        let unknown = [
+            key(PitchClass::Unknown, Mode::Major),
+            key(PitchClass::Unknown, Mode::Minor),
+            key(PitchClass::Unknown, Mode::Unknown),
+            key(PitchClass::FSharp, Mode::Unknown),
+        ];
+
+        for key in &unknown {
+            assert_eq!(key.notation(), None);
+            assert_eq!(key.camelot(), None);
+            assert_eq!(key.open_key(), None);
+            assert_eq!(key.to_string(), "unknown");
+        }
+    }
+
+    #[test]
+    fn pitch_class_round_trips_raw_integers() {
+        for value in -1..=11 {
+            let pitch_class: PitchClass = serde_json::from_str(&value.to_string()).unwrap();
+            assert_eq!(
+                serde_json::to_string(&pitch_class).unwrap(),
+                value.to_string()
+            );
+        }
+
+        let unknown: PitchClass = serde_json::from_str("-1").unwrap();
+        assert_eq!(unknown, PitchClass::Unknown);
+        assert!(serde_json::from_str::<PitchClass>("12").is_err());
+        assert!(serde_json::from_str::<PitchClass>("-2").is_err());
+    }
+
+    #[test]
+    fn mode_round_trips_raw_integers() {
+        let cases = [(-1, Mode::Unknown), (0, Mode::Minor), (1, Mode::Major)];
+
+        for (value, mode) in &cases {
+            let parsed: Mode = serde_json::from_str(&value.to_string()).unwrap();
+            assert_eq!(parsed, *mode);
+            assert_eq!(serde_json::to_string(mode).unwrap(), value.to_string());
+        }
+
+        assert!(serde_json::from_str::<Mode>("2").is_err());
+    }
 }
@@ -0,0 +1,138 @@
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Author {
+    pub name: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Narrator {
+    pub name: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Audiobook {
+    pub authors: Vec<Author>,
+    pub available_markets: Vec<String>,
+    pub copyrights: Vec<super::spotify_types::Copyright>,
+    pub description: String,
+    pub html_description: Option<String>,
+    pub edition: Option<String>,
+    pub explicit: bool,
+    pub external_urls: HashMap<String, String>,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<super::spotify_types::Image>,
+    pub languages: Vec<String>,
+    pub media_type: String,
+    pub name: String,
+    pub narrators: Vec<Narrator>,
+    pub publisher: String,
+    pub total_chapters: Option<u32>,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(remote = "Self")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SimplifiedChapter {
+    pub audio_preview_url: Option<String>,
+    pub available_markets: Option<Vec<String>>,
+    pub chapter_number: u32,
+    pub description: String,
+    pub html_description: Option<String>,
+    pub duration_ms: u32,
+    pub explicit: bool,
+    pub external_urls: HashMap<String, String>,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<super::spotify_types::Image>,
+    pub is_playable: Option<bool>,
+    pub languages: Vec<String>,
+    pub name: String,
+    pub release_date: super::spotify_types::ReleaseDate,
+    pub release_date_precision: String,
+    pub restrictions: Option<super::spotify_types::Restrictions>,
+    pub resume_point: Option<super::spotify_types::ResumePoint>,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Serialize for SimplifiedChapter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SimplifiedChapter::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimplifiedChapter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut chapter = SimplifiedChapter::deserialize(deserializer)?;
+        super::spotify_types::check_release_date(
+            &mut chapter.release_date,
+            &chapter.release_date_precision,
+        )?;
+        Ok(chapter)
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Chapter {
+    #[serde(flatten)]
+    pub chapter: SimplifiedChapter,
+    pub audiobook: Audiobook,
+}
+
+impl<'de> Deserialize<'de> for Chapter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut object = serde_json::Map::deserialize(deserializer)?;
+        let audiobook = super::take_field(&mut object, "audiobook")?;
+        let chapter =
+            serde_json::from_value(serde_json::Value::Object(object)).map_err(de::Error::custom)?;
+        Ok(Chapter { chapter, audiobook })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AudiobooksResponse {
+    pub audiobooks: Vec<Option<Audiobook>>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ChaptersResponse {
+    pub chapters: Vec<Option<Chapter>>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+pub type AudiobookChaptersResponse = super::CollectionResponse<SimplifiedChapter>;
+pub type UserAudiobookResponse = super::CollectionResponse<Audiobook>;
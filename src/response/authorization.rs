@@ -1,17 +1,27 @@
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct ClientAuthorizeResponse {
     pub access_token: String,
     pub token_type: String,
     pub expires_in: u32,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct UserAuthorizeResponse {
     pub access_token: String,
     pub token_type: String,
     pub scope: String,
     pub expires_in: u32,
     pub refresh_token: Option<String>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Category {
+    pub href: String,
+    pub icons: Vec<super::spotify_types::Image>,
+    pub id: String,
+    pub name: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Featured playlists along with the editorial message Spotify shows above them.
+#[derive(Serialize, Debug)]
+pub struct FeaturedPlaylists {
+    pub message: Option<String>,
+    pub playlists: Vec<super::playlist::UserPlaylist>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct NewReleasesResponse {
+    pub albums: super::CollectionResponse<super::spotify_types::SimplifiedAlbum>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BrowsePlaylistsResponse {
+    pub message: Option<String>,
+    pub playlists: super::CollectionResponse<super::playlist::UserPlaylist>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CategoriesResponse {
+    pub categories: super::CollectionResponse<Category>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SavedEpisode {
+    pub added_at: super::Timestamp,
+    pub episode: super::spotify_types::Episode,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl super::AddedAt for SavedEpisode {
+    fn added_at(&self) -> Option<&super::Timestamp> {
+        Some(&self.added_at)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EpisodesResponse {
+    pub episodes: Vec<Option<super::spotify_types::Episode>>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+pub type UserEpisodeResponse = super::CollectionResponse<SavedEpisode>;
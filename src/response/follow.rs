@@ -0,0 +1,11 @@
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FollowedArtistsResponse {
+    pub artists: super::CursorCollectionResponse<super::spotify_types::FullArtist>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
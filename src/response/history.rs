@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlayHistory {
+    pub track: super::spotify_types::FullTrack,
+    pub played_at: super::Timestamp,
+    pub context: Option<super::player::Context>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+pub type RecentlyPlayedResponse = super::CursorCollectionResponse<PlayHistory>;
+pub type TopArtistsResponse = super::CollectionResponse<super::spotify_types::FullArtist>;
+pub type TopTracksResponse = super::CollectionResponse<super::spotify_types::FullTrack>;
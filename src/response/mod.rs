@@ -1,14 +1,63 @@
-use serde::Deserialize;
+use serde::de::{self, DeserializeOwned};
+use serde::{Deserialize, Serialize};
 
 pub mod album;
+pub mod audio_analysis;
 pub mod audio_features;
+pub mod audiobook;
 pub mod authorization;
+pub mod browse;
+pub mod episode;
+pub mod follow;
+pub mod history;
+pub mod market;
+pub mod player;
 pub mod playlist;
+pub mod recommendations;
 pub mod search;
+pub mod show;
 pub mod spotify_types;
 pub mod track;
+pub mod user;
 
-#[derive(Deserialize, Debug)]
+/// A UTC point in time such as `added_at` or `played_at`. Parsed into a
+/// `chrono::DateTime` when the `chrono` feature is enabled, and kept as
+/// spotify's ISO 8601 string otherwise.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// Removes `key` from an object and reads it, reading a missing key as null.
+///
+/// Models that flatten another model read their own keys with this and hand
+/// the rest of the object to the flattened model, which then sees every
+/// unknown key. A derived `#[serde(flatten)]` hides them, so `strict` would
+/// accept them and `extra-fields` would drop them.
+pub(crate) fn take_field<T: DeserializeOwned, E: de::Error>(
+    object: &mut serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Result<T, E> {
+    let value = object.remove(key).unwrap_or(serde_json::Value::Null);
+    serde_json::from_value(value).map_err(|error| E::custom(format!("{}: {}", key, error)))
+}
+
+/// Library and playlist items that record when they were added.
+pub trait AddedAt {
+    fn added_at(&self) -> Option<&Timestamp>;
+
+    /// Whether the item was added within `period` of now.
+    #[cfg(feature = "chrono")]
+    fn added_within(&self, period: chrono::Duration) -> bool {
+        match self.added_at() {
+            Some(added_at) => *added_at >= chrono::Utc::now() - period,
+            None => false,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CollectionResponse<T> {
     pub href: String,
     pub items: Vec<T>,
@@ -17,4 +66,35 @@ pub struct CollectionResponse<T> {
     pub previous: Option<String>,
     pub offset: u32,
     pub total: u32,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Cursors {
+    pub after: Option<String>,
+    pub before: Option<String>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Paging object for endpoints that page by cursor instead of offset.
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CursorCollectionResponse<T> {
+    pub href: String,
+    pub items: Vec<T>,
+    pub limit: u32,
+    pub next: Option<String>,
+    pub cursors: Option<Cursors>,
+    pub total: Option<u32>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
@@ -0,0 +1,128 @@
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Device {
+    pub id: Option<String>,
+    pub is_active: bool,
+    pub is_private_session: bool,
+    pub is_restricted: bool,
+    pub name: String,
+    pub r#type: String,
+    pub volume_percent: Option<u32>,
+    pub supports_volume: Option<bool>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The album, artist, playlist or show that playback was started from.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Context {
+    pub r#type: String,
+    pub href: String,
+    pub external_urls: HashMap<String, String>,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RepeatState {
+    Track,
+    Context,
+    Off,
+}
+
+impl RepeatState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepeatState::Track => "track",
+            RepeatState::Context => "context",
+            RepeatState::Off => "off",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Actions {
+    pub disallows: HashMap<String, bool>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CurrentlyPlaying {
+    pub context: Option<Context>,
+    pub timestamp: u64,
+    pub progress_ms: Option<u64>,
+    pub is_playing: bool,
+    pub item: Option<super::spotify_types::PlayableItem>,
+    pub currently_playing_type: String,
+    pub actions: Option<Actions>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PlaybackState {
+    pub device: Device,
+    pub repeat_state: RepeatState,
+    pub shuffle_state: bool,
+    pub smart_shuffle: Option<bool>,
+    #[serde(flatten)]
+    pub playing: CurrentlyPlaying,
+}
+
+impl<'de> Deserialize<'de> for PlaybackState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut object = serde_json::Map::deserialize(deserializer)?;
+        let device = super::take_field(&mut object, "device")?;
+        let repeat_state = super::take_field(&mut object, "repeat_state")?;
+        let shuffle_state = super::take_field(&mut object, "shuffle_state")?;
+        let smart_shuffle = super::take_field(&mut object, "smart_shuffle")?;
+        let playing =
+            serde_json::from_value(serde_json::Value::Object(object)).map_err(de::Error::custom)?;
+        Ok(PlaybackState {
+            device,
+            repeat_state,
+            shuffle_state,
+            smart_shuffle,
+            playing,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Queue {
+    pub currently_playing: Option<super::spotify_types::PlayableItem>,
+    pub queue: Vec<super::spotify_types::PlayableItem>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DevicesResponse {
+    pub devices: Vec<Device>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -1,22 +1,140 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct PlaylistTrack {
-    pub added_at: Option<String>,
+    pub added_at: Option<super::Timestamp>,
+    /// `None` for items added before spotify started recording who added them.
+    pub added_by: Option<super::user::User>,
     pub is_local: bool,
-    pub track: super::spotify_types::Track,
+    pub primary_color: Option<String>,
+    /// `None` when the item was removed from spotify.
+    pub track: Option<super::spotify_types::PlayableItem>,
+    pub video_thumbnail: Option<VideoThumbnail>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// The video thumbnail of a playlist item. `url` is `None` for items
+/// without a video.
 #[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct VideoThumbnail {
+    pub url: Option<String>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl super::AddedAt for PlaylistTrack {
+    fn added_at(&self) -> Option<&super::Timestamp> {
+        self.added_at.as_ref()
+    }
+}
+
+impl PlaylistTrack {
+    /// The item's track if it is a playable catalog track, skipping
+    /// episodes, local files and removed items.
+    pub fn catalog_track(&self) -> Option<&super::spotify_types::FullTrack> {
+        self.track
+            .as_ref()
+            .and_then(|item| item.as_track())
+            .filter(|track| track.catalog_id().is_some())
+    }
+
+    /// The metadata of the local file this item points at, if it is one.
+    pub fn local_file(&self) -> Option<super::spotify_types::LocalFile> {
+        self.track
+            .as_ref()
+            .and_then(|item| item.as_track())
+            .and_then(|track| track.local_file())
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct UserPlaylist {
     pub collaborative: bool,
     pub description: Option<String>,
+    pub external_urls: HashMap<String, String>,
+    pub href: String,
+    pub id: String,
+    pub images: Option<Vec<super::spotify_types::Image>>,
+    pub name: String,
+    pub owner: super::user::User,
+    pub primary_color: Option<String>,
+    pub public: Option<bool>,
+    pub snapshot_id: String,
+    pub tracks: PlaylistTracksRef,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Where to fetch a playlist's items from, and how many there are.
+#[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PlaylistTracksRef {
+    pub href: String,
+    pub total: u32,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A playlist with its metadata and the first page of its items.
+#[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Playlist {
+    pub collaborative: bool,
+    pub description: Option<String>,
+    pub external_urls: HashMap<String, String>,
+    pub followers: super::spotify_types::Followers,
     pub href: String,
     pub id: String,
+    pub images: Option<Vec<super::spotify_types::Image>>,
     pub name: String,
-    pub public: bool,
+    pub owner: super::user::User,
+    pub primary_color: Option<String>,
+    pub public: Option<bool>,
     pub snapshot_id: String,
+    pub tracks: PlaylistTracksResponse,
+    pub r#type: String,
     pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A page of playlist items fetched with a `fields` projection.
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PartialPage<T> {
+    pub items: Vec<T>,
+    pub next: Option<String>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SnapshotResponse {
+    pub snapshot_id: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 pub type PlaylistTracksResponse = super::CollectionResponse<PlaylistTrack>;
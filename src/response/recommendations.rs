@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// How many tracks were available for a single seed at each stage of filtering.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RecommendationSeed {
+    pub after_filtering_size: u32,
+    pub after_relinking_size: u32,
+    pub href: Option<String>,
+    pub id: String,
+    pub initial_pool_size: u32,
+    pub r#type: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Recommendations {
+    pub seeds: Vec<RecommendationSeed>,
+    pub tracks: Vec<super::spotify_types::FullTrack>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GenreSeedsResponse {
+    pub genres: Vec<String>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
@@ -1,12 +1,22 @@
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct TrackResponse {
     pub href: String,
-    pub items: Vec<super::spotify_types::Track>,
+    pub items: Vec<super::spotify_types::FullTrack>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct SearchTrackResponse {
     pub tracks: TrackResponse,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
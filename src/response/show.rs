@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SavedShow {
+    pub added_at: super::Timestamp,
+    pub show: super::spotify_types::Show,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl super::AddedAt for SavedShow {
+    fn added_at(&self) -> Option<&super::Timestamp> {
+        Some(&self.added_at)
+    }
+}
+
+pub type ShowEpisodesResponse = super::CollectionResponse<super::spotify_types::SimplifiedEpisode>;
+pub type UserShowResponse = super::CollectionResponse<SavedShow>;
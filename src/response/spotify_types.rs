@@ -1,50 +1,854 @@
-use serde::{Deserialize, Serialize};
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use url::form_urlencoded;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct AlbumCover {
-    pub height: u32,
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Image {
+    pub height: Option<u32>,
     pub url: String,
-    pub width: u32,
+    pub width: Option<u32>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Image {
+    /// Width and height, when spotify reports them.
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        match (self.width, self.height) {
+            (Some(width), Some(height)) => Some((width, height)),
+            _ => None,
+        }
+    }
+
+    /// The image's shorter side, which is what a square crop can use.
+    fn size(&self) -> Option<u32> {
+        self.dimensions().map(|(width, height)| width.min(height))
+    }
+}
+
+/// Picks an image from the list spotify returns for an album, artist,
+/// playlist or user. Images without dimensions are only chosen when none of
+/// the images have them. Spotify lists images largest first, so then
+/// `largest` falls back to the first image and `smallest` to the last.
+pub trait ImageSelection {
+    /// The image with the shortest side.
+    fn smallest(&self) -> Option<&Image>;
+
+    /// The image with the longest shorter side.
+    fn largest(&self) -> Option<&Image>;
+
+    /// The smallest image at least `px` pixels on its shorter side, falling
+    /// back to the largest image when none are big enough.
+    fn best_image_at_least(&self, px: u32) -> Option<&Image>;
+}
+
+impl ImageSelection for [Image] {
+    fn smallest(&self) -> Option<&Image> {
+        self.iter()
+            .filter(|image| image.size().is_some())
+            .min_by_key(|image| image.size())
+            .or_else(|| self.last())
+    }
+
+    fn largest(&self) -> Option<&Image> {
+        self.iter()
+            .filter(|image| image.size().is_some())
+            .max_by_key(|image| image.size())
+            .or_else(|| self.first())
+    }
+
+    fn best_image_at_least(&self, px: u32) -> Option<&Image> {
+        self.iter()
+            .filter(|image| image.size() >= Some(px))
+            .min_by_key(|image| image.size())
+            .or_else(|| self.largest())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Followers {
+    pub href: Option<String>,
+    pub total: u32,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Copyright {
+    pub text: String,
+    pub r#type: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A release date as precise as spotify knows it: a year, a month or a day.
+///
+/// A date stands for the whole period it names, so dates of different
+/// precision have no total order. `is_after` and `is_before` compare periods
+/// and are both false when the periods overlap, e.g. `2020` and `2020-03-01`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct ReleaseDate {
+    pub year: i32,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+impl ReleaseDate {
+    /// The matching `release_date_precision` value.
+    pub fn precision(&self) -> &'static str {
+        match (self.month, self.day) {
+            (_, Some(_)) => "day",
+            (Some(_), None) => "month",
+            (None, None) => "year",
+        }
+    }
+
+    /// The date cut to a `release_date_precision` value. Fails when the date
+    /// is less precise than the precision asks for.
+    pub fn with_precision(&self, precision: &str) -> Result<ReleaseDate, String> {
+        match precision {
+            "year" => Ok(ReleaseDate {
+                month: None,
+                day: None,
+                ..*self
+            }),
+            "month" if self.month.is_some() => Ok(ReleaseDate { day: None, ..*self }),
+            "day" if self.day.is_some() => Ok(*self),
+            "month" | "day" => Err(format!(
+                "release date {} is less precise than its {} precision",
+                self, precision
+            )),
+            _ => Err(format!("invalid release date precision {}", precision)),
+        }
+    }
+
+    /// Whether this period starts after `other` ends, e.g. `2021` and
+    /// `2021-01-01` are after `2020`, but `2020-12-31` is not.
+    pub fn is_after(&self, other: &ReleaseDate) -> bool {
+        self.first_day() > other.last_day()
+    }
+
+    /// Whether this period ends before `other` starts.
+    pub fn is_before(&self, other: &ReleaseDate) -> bool {
+        self.last_day() < other.first_day()
+    }
+
+    /// The first day of the period as `(year, month, day)`.
+    fn first_day(&self) -> (i32, u8, u8) {
+        (self.year, self.month.unwrap_or(1), self.day.unwrap_or(1))
+    }
+
+    /// The last day of the period as `(year, month, day)`.
+    fn last_day(&self) -> (i32, u8, u8) {
+        let month = self.month.unwrap_or(12);
+        let day = self.day.unwrap_or_else(|| days_in_month(self.year, month));
+        (self.year, month, day)
+    }
+}
+
+impl FromStr for ReleaseDate {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "invalid release date {}, expected YYYY, YYYY-MM or YYYY-MM-DD",
+                value
+            )
+        };
+        let parts: Vec<&str> = value.split('-').collect();
+        let (year, month, day) = match parts.as_slice() {
+            [year] => (year, None, None),
+            [year, month] => (year, Some(month), None),
+            [year, month, day] => (year, Some(month), Some(day)),
+            _ => return Err(invalid()),
+        };
+
+        let year = parse_digits(year, 4).ok_or_else(invalid)?;
+        let month = match month {
+            None => None,
+            Some(month) => match parse_digits(month, 2) {
+                Some(month @ 1..=12) => Some(month),
+                _ => return Err(invalid()),
+            },
+        };
+        let day = match (month, day) {
+            (_, None) => None,
+            (Some(month), Some(day)) => match parse_digits(day, 2) {
+                Some(day) if day >= 1 && day <= days_in_month(year, month) => Some(day),
+                _ => return Err(invalid()),
+            },
+            (None, Some(_)) => return Err(invalid()),
+        };
+
+        Ok(ReleaseDate { year, month, day })
+    }
+}
+
+/// Parses a field of exactly `len` ascii digits.
+fn parse_digits<T: FromStr>(value: &str, len: usize) -> Option<T> {
+    if value.len() == len && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Cuts a `release_date` read from an object to the object's
+/// `release_date_precision`.
+pub(crate) fn check_release_date<E: de::Error>(
+    date: &mut ReleaseDate,
+    precision: &str,
+) -> Result<(), E> {
+    *date = date.with_precision(precision).map_err(E::custom)?;
+    Ok(())
+}
+
+impl TryFrom<String> for ReleaseDate {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
 }
 
+impl From<ReleaseDate> for String {
+    fn from(date: ReleaseDate) -> Self {
+        date.to_string()
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ResumePoint {
+    pub fully_played: bool,
+    pub resume_position_ms: u64,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Restrictions {
+    pub reason: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The album object nested in tracks and returned by listings such as new
+/// releases.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(remote = "Self")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SimplifiedAlbum {
+    // local files carry an album with only a name, so most fields are nullable
+    pub album_group: Option<String>,
+    pub album_type: Option<String>,
+    pub artists: Vec<SimplifiedArtist>,
+    pub available_markets: Option<Vec<String>>,
+    #[serde(default)]
+    pub external_urls: HashMap<String, String>,
+    pub href: Option<String>,
+    pub id: Option<String>,
+    pub images: Vec<Image>,
+    pub name: String,
+    pub release_date: Option<ReleaseDate>,
+    pub release_date_precision: Option<String>,
+    pub restrictions: Option<Restrictions>,
+    pub total_tracks: Option<u32>,
+    pub r#type: String,
+    pub uri: Option<String>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// the derives go through `remote = "Self"` so the release date can be cut to
+// its precision once the whole object is read
+impl Serialize for SimplifiedAlbum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SimplifiedAlbum::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimplifiedAlbum {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut album = SimplifiedAlbum::deserialize(deserializer)?;
+        if let (Some(date), Some(precision)) =
+            (&mut album.release_date, &album.release_date_precision)
+        {
+            check_release_date(date, precision)?;
+        }
+        Ok(album)
+    }
+}
+
+/// The album object returned when fetching albums directly.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Album {
+#[serde(remote = "Self")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FullAlbum {
     pub album_type: String,
-    pub available_markets: Vec<String>,
+    pub artists: Vec<SimplifiedArtist>,
+    pub available_markets: Option<Vec<String>>,
+    pub copyrights: Vec<Copyright>,
+    pub external_ids: HashMap<String, String>,
+    pub external_urls: HashMap<String, String>,
+    pub genres: Vec<String>,
     pub href: String,
     pub id: String,
-    pub images: Vec<AlbumCover>,
+    pub images: Vec<Image>,
+    pub label: Option<String>,
     pub name: String,
-    pub release_date: String,
+    pub popularity: u32,
+    pub release_date: ReleaseDate,
     pub release_date_precision: String,
+    pub restrictions: Option<Restrictions>,
     pub total_tracks: u32,
+    pub tracks: super::CollectionResponse<SimplifiedTrack>,
     pub r#type: String,
     pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Serialize for FullAlbum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FullAlbum::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FullAlbum {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut album = FullAlbum::deserialize(deserializer)?;
+        check_release_date(&mut album.release_date, &album.release_date_precision)?;
+        Ok(album)
+    }
+}
+
+/// The artist object nested in tracks and albums.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SimplifiedArtist {
+    #[serde(default)]
+    pub external_urls: HashMap<String, String>,
+    pub href: Option<String>,
+    pub id: Option<String>,
+    pub name: String,
+    pub r#type: String,
+    pub uri: Option<String>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// The artist object returned when fetching artists directly.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Artist {
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FullArtist {
+    pub external_urls: HashMap<String, String>,
+    pub followers: Followers,
+    pub genres: Vec<String>,
     pub href: String,
     pub id: String,
+    pub images: Vec<Image>,
     pub name: String,
+    pub popularity: u32,
     pub r#type: String,
     pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// The original track a relinked track stands in for.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Track {
-    pub album: Option<Album>,
-    pub artists: Vec<Artist>,
-    pub available_markets: Vec<String>,
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TrackLink {
+    pub external_urls: HashMap<String, String>,
+    pub href: String,
+    pub id: String,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The track object returned by album track listings, which has no album.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SimplifiedTrack {
+    pub artists: Vec<SimplifiedArtist>,
+    pub available_markets: Option<Vec<String>>,
     pub disc_number: u32,
     pub duration_ms: u32,
     pub explicit: bool,
-    pub href: String,
-    pub id: String,
+    #[serde(default)]
+    pub external_urls: HashMap<String, String>,
+    pub href: Option<String>,
+    pub id: Option<String>,
+    #[serde(default)]
+    pub is_local: bool,
+    pub is_playable: Option<bool>,
+    pub linked_from: Option<TrackLink>,
+    pub name: String,
+    pub preview_url: Option<String>,
+    pub restrictions: Option<Restrictions>,
+    pub track_number: u32,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl SimplifiedTrack {
+    /// The catalog id, or `None` for local files.
+    pub fn catalog_id(&self) -> Option<&str> {
+        match self.is_local {
+            true => None,
+            false => self.id.as_deref(),
+        }
+    }
+
+    /// The id that was asked for, before any relinking.
+    pub fn requested_id(&self) -> Option<&str> {
+        match &self.linked_from {
+            Some(link) => Some(&link.id),
+            None => self.catalog_id(),
+        }
+    }
+}
+
+/// The track object returned everywhere a track is fetched on its own.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FullTrack {
+    pub album: SimplifiedAlbum,
+    pub artists: Vec<SimplifiedArtist>,
+    pub available_markets: Option<Vec<String>>,
+    pub disc_number: u32,
+    pub duration_ms: u32,
+    /// Set on tracks inside playlist items, `false` for tracks.
+    pub episode: Option<bool>,
+    pub explicit: bool,
+    #[serde(default)]
+    pub external_ids: HashMap<String, String>,
+    #[serde(default)]
+    pub external_urls: HashMap<String, String>,
+    pub href: Option<String>,
+    /// `None` for local files, which are not in the spotify catalog.
+    pub id: Option<String>,
+    #[serde(default)]
+    pub is_local: bool,
+    pub is_playable: Option<bool>,
+    pub linked_from: Option<TrackLink>,
     pub name: String,
-    pub popularity: Option<u32>,
+    pub popularity: u32,
     pub preview_url: Option<String>,
+    pub restrictions: Option<Restrictions>,
+    /// Set on tracks inside playlist items, `true` for tracks.
+    pub track: Option<bool>,
     pub track_number: u32,
     pub r#type: String,
     pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FullTrack {
+    /// The catalog id, or `None` for local files.
+    pub fn catalog_id(&self) -> Option<&str> {
+        match self.is_local {
+            true => None,
+            false => self.id.as_deref(),
+        }
+    }
+
+    /// The id that was asked for, before any relinking.
+    pub fn requested_id(&self) -> Option<&str> {
+        match &self.linked_from {
+            Some(link) => Some(&link.id),
+            None => self.catalog_id(),
+        }
+    }
+
+    /// The metadata of a local file, read from its `spotify:local:` uri.
+    pub fn local_file(&self) -> Option<LocalFile> {
+        if !self.is_local {
+            return None;
+        }
+
+        // spotify:local:<artist>:<album>:<title>:<seconds>, each part form-encoded
+        let parts: Vec<String> = self
+            .uri
+            .split(':')
+            .skip(2)
+            .map(|part| {
+                form_urlencoded::parse(format!("x={}", part).as_bytes())
+                    .next()
+                    .map(|(_, value)| value.into_owned())
+                    .unwrap_or_default()
+            })
+            .collect();
+        let part = |index: usize| parts.get(index).filter(|part| !part.is_empty()).cloned();
+
+        Some(LocalFile {
+            artist: part(0),
+            album: part(1),
+            title: part(2).unwrap_or_else(|| self.name.clone()),
+            duration_ms: self.duration_ms,
+        })
+    }
+}
+
+/// A file from the user's device that was added to a playlist.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LocalFile {
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub title: String,
+    pub duration_ms: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Show {
+    pub available_markets: Vec<String>,
+    pub copyrights: Vec<Copyright>,
+    pub description: String,
+    pub html_description: Option<String>,
+    pub explicit: bool,
+    pub external_urls: HashMap<String, String>,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    pub is_externally_hosted: Option<bool>,
+    pub languages: Vec<String>,
+    pub media_type: String,
+    pub name: String,
+    pub publisher: String,
+    pub total_episodes: Option<u32>,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(remote = "Self")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SimplifiedEpisode {
+    pub audio_preview_url: Option<String>,
+    pub description: String,
+    pub html_description: Option<String>,
+    pub duration_ms: u32,
+    pub explicit: bool,
+    pub external_urls: HashMap<String, String>,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<Image>,
+    pub is_externally_hosted: bool,
+    pub is_playable: Option<bool>,
+    /// Deprecated by spotify in favour of `languages`.
+    pub language: Option<String>,
+    pub languages: Vec<String>,
+    pub name: String,
+    pub release_date: ReleaseDate,
+    pub release_date_precision: String,
+    pub restrictions: Option<Restrictions>,
+    pub resume_point: Option<ResumePoint>,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Serialize for SimplifiedEpisode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SimplifiedEpisode::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SimplifiedEpisode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut episode = SimplifiedEpisode::deserialize(deserializer)?;
+        check_release_date(&mut episode.release_date, &episode.release_date_precision)?;
+        Ok(episode)
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct Episode {
+    #[serde(flatten)]
+    pub episode: SimplifiedEpisode,
+    pub show: Show,
+}
+
+impl<'de> Deserialize<'de> for Episode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut object = serde_json::Map::deserialize(deserializer)?;
+        let show = super::take_field(&mut object, "show")?;
+        let episode =
+            serde_json::from_value(serde_json::Value::Object(object)).map_err(de::Error::custom)?;
+        Ok(Episode { episode, show })
+    }
+}
+
+/// Anything that can sit in a playlist or the play queue.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum PlayableItem {
+    Track(Box<FullTrack>),
+    Episode(Box<Episode>),
+}
+
+impl PlayableItem {
+    /// The catalog id, or `None` for local files.
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            PlayableItem::Track(track) => track.catalog_id(),
+            PlayableItem::Episode(episode) => Some(&episode.episode.id),
+        }
+    }
+
+    pub fn as_track(&self) -> Option<&FullTrack> {
+        match self {
+            PlayableItem::Track(track) => Some(track.as_ref()),
+            PlayableItem::Episode(_) => None,
+        }
+    }
+
+    pub fn as_episode(&self) -> Option<&Episode> {
+        match self {
+            PlayableItem::Track(_) => None,
+            PlayableItem::Episode(episode) => Some(episode.as_ref()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PlayableItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // pick the variant from the `type` field so errors point at the right model
+        let value = serde_json::Value::deserialize(deserializer)?;
+        match value.get("type").and_then(|kind| kind.as_str()) {
+            Some("episode") => serde_json::from_value(value)
+                .map(|episode| PlayableItem::Episode(Box::new(episode)))
+                .map_err(de::Error::custom),
+            _ => serde_json::from_value(value)
+                .map(|track| PlayableItem::Track(Box::new(track)))
+                .map_err(de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(url: &str, side: Option<u32>) -> Image {
+        serde_json::from_value(serde_json::json!({
+            "height": side,
+            "url": url,
+            "width": side,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn selects_images_by_size() {
+        let images = [
+            image("640", Some(640)),
+            image("300", Some(300)),
+            image("64", Some(64)),
+        ];
+        assert_eq!(images.smallest().unwrap().url, "64");
+        assert_eq!(images.largest().unwrap().url, "640");
+        assert_eq!(images.best_image_at_least(200).unwrap().url, "300");
+        assert_eq!(images.best_image_at_least(1000).unwrap().url, "640");
+    }
+
+    #[test]
+    fn falls_back_to_image_order_without_dimensions() {
+        let images = [image("large", None), image("small", None)];
+        assert_eq!(images.smallest().unwrap().url, "small");
+        assert_eq!(images.largest().unwrap().url, "large");
+
+        let none: [Image; 0] = [];
+        assert!(none.smallest().is_none());
+    }
+
+    fn date(value: &str) -> ReleaseDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_release_dates() {
+        let cases = [
+            ("2020", (2020, None, None)),
+            ("2020-02", (2020, Some(2), None)),
+            ("2020-02-29", (2020, Some(2), Some(29))),
+            ("0000", (0, None, None)),
+        ];
+
+        for (value, (year, month, day)) in &cases {
+            let parsed = date(value);
+            assert_eq!(
+                (parsed.year, parsed.month, parsed.day),
+                (*year, *month, *day)
+            );
+            assert_eq!(parsed.to_string(), *value);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_release_dates() {
+        let cases = [
+            "",
+            "20-1",
+            "2020-1-5",
+            "2020-01-5",
+            "20201",
+            "2020-",
+            "2020-13",
+            "2020-00",
+            "2020-01-00",
+            "2020-01-32",
+            "2020-04-31",
+            "2020-02-30",
+            "2021-02-29",
+            "1900-02-29",
+            "2020-01-01-01",
+            "+020",
+            "2020-+1",
+        ];
+
+        for value in &cases {
+            assert!(value.parse::<ReleaseDate>().is_err(), "{}", value);
+        }
+        assert!("2000-02-29".parse::<ReleaseDate>().is_ok());
+    }
+
+    #[test]
+    fn compares_release_dates_of_one_precision() {
+        assert!(date("2021").is_after(&date("2020")));
+        assert!(date("2020-02").is_after(&date("2020-01")));
+        assert!(date("2020-01-02").is_after(&date("2020-01-01")));
+        assert!(date("2020-01-01").is_before(&date("2020-01-02")));
+
+        let same = date("2020-01");
+        assert!(!same.is_after(&same));
+        assert!(!same.is_before(&same));
+    }
+
+    #[test]
+    fn compares_release_dates_of_mixed_precision() {
+        // periods that overlap are neither before nor after each other
+        let overlapping = [
+            ("2020", "2020-03-01"),
+            ("2020", "2020-12"),
+            ("2020-02", "2020-02-29"),
+            ("2020", "2020-01-01"),
+        ];
+        for (wide, narrow) in &overlapping {
+            let (wide, narrow) = (date(wide), date(narrow));
+            assert!(!narrow.is_after(&wide) && !narrow.is_before(&wide));
+            assert!(!wide.is_after(&narrow) && !wide.is_before(&narrow));
+        }
+
+        assert!(date("2021-01-01").is_after(&date("2020")));
+        assert!(date("2021").is_after(&date("2020-12-31")));
+        assert!(date("2020-03").is_after(&date("2020-02-29")));
+        assert!(date("2019-12-31").is_before(&date("2020")));
+        assert!(date("2019").is_before(&date("2020-01")));
+        assert!(!date("2020-12-31").is_after(&date("2020")));
+    }
+
+    #[test]
+    fn release_date_round_trips_as_string() {
+        for value in &["\"1999\"", "\"1999-07\"", "\"1999-07-04\""] {
+            let parsed: ReleaseDate = serde_json::from_str(value).unwrap();
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), *value);
+        }
+        assert!(serde_json::from_str::<ReleaseDate>("\"1999-7\"").is_err());
+    }
+
+    fn album(release_date: &str, precision: &str) -> Result<SimplifiedAlbum, serde_json::Error> {
+        serde_json::from_value(serde_json::json!({
+            "artists": [],
+            "images": [],
+            "name": "album",
+            "release_date": release_date,
+            "release_date_precision": precision,
+            "type": "album",
+        }))
+    }
+
+    #[test]
+    fn cuts_release_date_to_precision() {
+        let cut = album("1981-12-01", "year").unwrap();
+        assert_eq!(cut.release_date, Some(date("1981")));
+        let value = serde_json::to_value(&cut).unwrap();
+        assert_eq!(value["release_date"], "1981");
+
+        let exact = album("1981-12", "month").unwrap();
+        assert_eq!(exact.release_date, Some(date("1981-12")));
+
+        assert!(album("1981", "day").is_err());
+        assert!(album("1981-12", "week").is_err());
+    }
 }
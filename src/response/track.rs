@@ -1,9 +1,30 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct SavedTrack {
-    pub added_at: Option<String>,
-    pub track: super::spotify_types::Track,
+    pub added_at: Option<super::Timestamp>,
+    pub track: super::spotify_types::FullTrack,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl super::AddedAt for SavedTrack {
+    fn added_at(&self) -> Option<&super::Timestamp> {
+        self.added_at.as_ref()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TracksResponse {
+    pub tracks: Vec<Option<super::spotify_types::FullTrack>>,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 pub type SavedTrackResponse = super::CollectionResponse<SavedTrack>;
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ExplicitContent {
+    pub filter_enabled: bool,
+    pub filter_locked: bool,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Another user's public profile. `followers` and `images` are left out when
+/// the user is embedded in another object, such as a playlist owner.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct User {
+    pub display_name: Option<String>,
+    pub external_urls: HashMap<String, String>,
+    pub followers: Option<super::spotify_types::Followers>,
+    pub href: String,
+    pub id: String,
+    pub images: Option<Vec<super::spotify_types::Image>>,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The profile of the user the client is authorized as. `country`, `product`
+/// and `explicit_content` need the `user-read-private` scope, `email` needs
+/// `user-read-email`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CurrentUser {
+    pub country: Option<String>,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub explicit_content: Option<ExplicitContent>,
+    pub external_urls: HashMap<String, String>,
+    pub followers: super::spotify_types::Followers,
+    pub href: String,
+    pub id: String,
+    pub images: Vec<super::spotify_types::Image>,
+    pub product: Option<String>,
+    pub r#type: String,
+    pub uri: String,
+
+    #[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
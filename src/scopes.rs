@@ -0,0 +1,17 @@
+//! Authorization scopes, to be joined with spaces and passed to `RSpotify::new`.
+
+pub const PLAYLIST_READ_PRIVATE: &str = "playlist-read-private";
+pub const PLAYLIST_MODIFY_PUBLIC: &str = "playlist-modify-public";
+pub const PLAYLIST_MODIFY_PRIVATE: &str = "playlist-modify-private";
+pub const UGC_IMAGE_UPLOAD: &str = "ugc-image-upload";
+pub const USER_FOLLOW_READ: &str = "user-follow-read";
+pub const USER_FOLLOW_MODIFY: &str = "user-follow-modify";
+pub const USER_LIBRARY_READ: &str = "user-library-read";
+pub const USER_LIBRARY_MODIFY: &str = "user-library-modify";
+pub const USER_READ_RECENTLY_PLAYED: &str = "user-read-recently-played";
+pub const USER_TOP_READ: &str = "user-top-read";
+pub const USER_READ_PRIVATE: &str = "user-read-private";
+pub const USER_READ_EMAIL: &str = "user-read-email";
+pub const USER_READ_PLAYBACK_STATE: &str = "user-read-playback-state";
+pub const USER_MODIFY_PLAYBACK_STATE: &str = "user-modify-playback-state";
+pub const USER_READ_CURRENTLY_PLAYING: &str = "user-read-currently-playing";
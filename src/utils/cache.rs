@@ -0,0 +1,31 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Holds a single value for a fixed time, for reference data that rarely
+/// changes, such as the list of markets.
+pub struct TtlCache<T> {
+    ttl: Duration,
+    entry: Mutex<Option<(Instant, T)>>,
+}
+
+impl<T: Clone> TtlCache<T> {
+    pub fn new(ttl: Duration) -> Self {
+        TtlCache {
+            ttl,
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// The cached value, unless it is missing or has expired.
+    pub fn get(&self) -> Option<T> {
+        let entry = self.entry.lock().unwrap();
+        match entry.as_ref() {
+            Some((stored_at, value)) if stored_at.elapsed() < self.ttl => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn set(&self, value: T) {
+        *self.entry.lock().unwrap() = Some((Instant::now(), value));
+    }
+}
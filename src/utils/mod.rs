@@ -1,3 +1,4 @@
+pub mod cache;
 pub mod raxios;
 pub mod requests;
 
@@ -11,6 +12,7 @@ use std::path::Path;
 use url::Url;
 
 const SPOTIFY_AUTHORIZATION_URL: &'static str = "https://accounts.spotify.com/authorize";
+const MAX_COVER_IMAGE_SIZE: usize = 256 * 1024;
 
 fn handle_request(mut stream: TcpStream, spotify_url: &str) -> Result<String, ()> {
     let this_server = Url::parse("http://127.0.0.1:8008").unwrap();
@@ -163,3 +165,27 @@ pub fn write_to_auth_cache(key: &str, value: &str) -> std::io::Result<()> {
 
     Ok(())
 }
+
+/// Checks that `image` is a JPEG and base64-encodes it for a cover upload,
+/// failing if the encoded payload is over Spotify's 256 KB limit.
+pub fn encode_cover_image(image: &[u8]) -> std::io::Result<String> {
+    if !image.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        let err = Error::new(ErrorKind::InvalidInput, "cover image must be a JPEG");
+        return Err(err);
+    }
+
+    let encoded = base64::encode(image);
+    if encoded.len() > MAX_COVER_IMAGE_SIZE {
+        let err = Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "cover image is {} bytes once encoded, the limit is {}",
+                encoded.len(),
+                MAX_COVER_IMAGE_SIZE
+            ),
+        );
+        return Err(err);
+    }
+
+    Ok(encoded)
+}
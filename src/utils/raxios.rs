@@ -1,6 +1,6 @@
-use reqwest::{header, Client, Error};
+use reqwest::{header, Client, Error, Method, RequestBuilder, StatusCode};
 use serde::Deserialize;
-use std::collections::HashMap;
+use serde_json::Value;
 
 pub struct RaxiosClient {
     token: Option<String>,
@@ -37,19 +37,117 @@ impl RaxiosClient {
         response.json::<T>().await
     }
 
-    pub async fn _post<T: for<'de> Deserialize<'de>>(
+    /// Like `get`, but maps an empty `204 No Content` response to `None`.
+    pub async fn get_optional<T: for<'de> Deserialize<'de>>(
         &self,
         url: &str,
-        body: Option<HashMap<String, String>>,
+        headers: Option<header::HeaderMap>,
+    ) -> Result<Option<T>, Error> {
+        let request_builder = self.build_request(Method::GET, url, None, headers);
+
+        let response = request_builder.send().await?.error_for_status()?;
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+
+        response.json::<T>().await.map(Some)
+    }
+
+    /// Like `get`, but maps `404 Not Found` to `None` and fails on any other
+    /// error status, so a missing object is told apart from a failed request.
+    pub async fn get_found<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        headers: Option<header::HeaderMap>,
+    ) -> Result<Option<T>, Error> {
+        let request_builder = self.build_request(Method::GET, url, None, headers);
+
+        let response = request_builder.send().await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        response.error_for_status()?.json::<T>().await.map(Some)
+    }
+
+    /// Sends a write request and deserializes the JSON body of the response.
+    pub async fn send<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&Value>,
         headers: Option<header::HeaderMap>,
     ) -> Result<T, Error> {
-        let client = Client::new();
-        let request_builder = client.get(url);
+        let request_builder = self.build_request(method, url, body, headers);
 
-        let request_builder = match body {
-            None => request_builder,
-            Some(body) => request_builder.json(&body),
-        };
+        let response = request_builder.send().await?.error_for_status()?;
+        response.json::<T>().await
+    }
+
+    /// Like `send`, but maps an empty response to `None` for endpoints that
+    /// may or may not answer with a body.
+    pub async fn send_optional<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&Value>,
+        headers: Option<header::HeaderMap>,
+    ) -> Result<Option<T>, Error> {
+        let request_builder = self.build_request(method, url, body, headers);
+
+        let response = request_builder.send().await?.error_for_status()?;
+        if response.status() == StatusCode::NO_CONTENT || response.content_length() == Some(0) {
+            return Ok(None);
+        }
+
+        response.json::<T>().await.map(Some)
+    }
+
+    pub async fn post(
+        &self,
+        url: &str,
+        body: Option<&Value>,
+        headers: Option<header::HeaderMap>,
+    ) -> Result<(), Error> {
+        let request_builder = self.build_request(Method::POST, url, body, headers);
+
+        request_builder.send().await?.error_for_status()?;
+        Ok(())
+    }
+
+    pub async fn put(
+        &self,
+        url: &str,
+        body: Option<&Value>,
+        headers: Option<header::HeaderMap>,
+    ) -> Result<(), Error> {
+        let request_builder = self.build_request(Method::PUT, url, body, headers);
+
+        request_builder.send().await?.error_for_status()?;
+        Ok(())
+    }
+
+    pub async fn delete(
+        &self,
+        url: &str,
+        body: Option<&Value>,
+        headers: Option<header::HeaderMap>,
+    ) -> Result<(), Error> {
+        let request_builder = self.build_request(Method::DELETE, url, body, headers);
+
+        request_builder.send().await?.error_for_status()?;
+        Ok(())
+    }
+
+    /// Sends `data` as the raw request body, e.g. base64 image uploads.
+    pub async fn put_data(
+        &self,
+        url: &str,
+        data: String,
+        headers: Option<header::HeaderMap>,
+    ) -> Result<(), Error> {
+        let client = Client::new();
+        let request_builder = client.put(url).body(data);
 
         let request_builder = match &self.token {
             None => request_builder,
@@ -61,7 +159,34 @@ impl RaxiosClient {
             Some(headers) => request_builder.headers(headers),
         };
 
-        let response = request_builder.send().await?;
-        response.json::<T>().await
+        request_builder.send().await?.error_for_status()?;
+        Ok(())
+    }
+
+    fn build_request(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<&Value>,
+        headers: Option<header::HeaderMap>,
+    ) -> RequestBuilder {
+        let client = Client::new();
+        let request_builder = client.request(method, url);
+
+        // spotify rejects body-less requests that don't declare a length
+        let request_builder = match body {
+            None => request_builder.header(header::CONTENT_LENGTH, 0),
+            Some(body) => request_builder.json(body),
+        };
+
+        let request_builder = match &self.token {
+            None => request_builder,
+            Some(token) => request_builder.bearer_auth(token),
+        };
+
+        match headers {
+            None => request_builder,
+            Some(headers) => request_builder.headers(headers),
+        }
     }
 }
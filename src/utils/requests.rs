@@ -1,14 +1,137 @@
 use super::raxios::RaxiosClient;
-use crate::response::album::{AlbumTracksResponse, UserAlbumResponse};
+use crate::id::{
+    join_ids, AlbumId, AudiobookId, ChapterId, EpisodeId, Id, PlayableId, TrackId, UserId,
+};
+use crate::params::fields::FieldsFilter;
+use crate::params::history::{PlayedCursor, TimeRange};
+use crate::params::market::{Country, Market};
+use crate::params::player::PlayOptions;
+use crate::params::playlist::{AdditionalType, PlaylistDetails, RemoveItem};
+use crate::params::recommendations::RecommendationsOptions;
+use crate::response::album::{AlbumTracksResponse, AlbumsResponse, UserAlbumResponse};
+use crate::response::audio_analysis::AudioAnalysis;
 use crate::response::audio_features::AudioFeaturesResponse;
-use crate::response::playlist::{PlaylistTracksResponse, UserPlaylistResponse};
+use crate::response::audiobook::{
+    Audiobook, AudiobookChaptersResponse, AudiobooksResponse, Chapter, ChaptersResponse,
+    UserAudiobookResponse,
+};
+use crate::response::browse::{
+    BrowsePlaylistsResponse, CategoriesResponse, Category, NewReleasesResponse,
+};
+use crate::response::episode::{EpisodesResponse, UserEpisodeResponse};
+use crate::response::follow::FollowedArtistsResponse;
+use crate::response::history::{RecentlyPlayedResponse, TopArtistsResponse, TopTracksResponse};
+use crate::response::market::MarketsResponse;
+use crate::response::player::{
+    CurrentlyPlaying, DevicesResponse, PlaybackState, Queue, RepeatState,
+};
+use crate::response::playlist::{
+    PartialPage, PlaylistTracksResponse, SnapshotResponse, UserPlaylist, UserPlaylistResponse,
+};
+use crate::response::recommendations::{GenreSeedsResponse, Recommendations};
 use crate::response::search::SearchTrackResponse;
-use crate::response::track::SavedTrackResponse;
+use crate::response::show::{ShowEpisodesResponse, UserShowResponse};
+use crate::response::spotify_types::{Episode, FullAlbum, FullTrack, Image, Show};
+use crate::response::track::{SavedTrackResponse, TracksResponse};
+use crate::response::user::{CurrentUser, User};
+use crate::RawRequestError;
 use log::info;
-use reqwest::Error;
+use reqwest::{header, Error, Method};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use url::{form_urlencoded, ParseError, Url};
 
 const SPOTIFY_URL: &'static str = "https://api.spotify.com/v1";
 
+/// The kinds of item that can be saved to the user's library.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LibraryKind {
+    Tracks,
+    Albums,
+    Shows,
+    Episodes,
+    Audiobooks,
+}
+
+impl LibraryKind {
+    fn path(&self) -> &'static str {
+        match self {
+            LibraryKind::Tracks => "tracks",
+            LibraryKind::Albums => "albums",
+            LibraryKind::Shows => "shows",
+            LibraryKind::Episodes => "episodes",
+            LibraryKind::Audiobooks => "audiobooks",
+        }
+    }
+
+    /// Most ids Spotify accepts in one save, remove or contains request.
+    pub fn chunk_size(&self) -> usize {
+        match self {
+            LibraryKind::Albums => 20,
+            _ => 50,
+        }
+    }
+}
+
+/// The kinds of account the user can follow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FollowKind {
+    Artist,
+    User,
+}
+
+impl FollowKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FollowKind::Artist => "artist",
+            FollowKind::User => "user",
+        }
+    }
+}
+
+/// Resolves a path relative to the API root. Full URLs such as `next` links
+/// are only accepted when they point at the API, since the request carries
+/// the bearer token.
+fn raw_url(path: &str) -> Result<String, RawRequestError> {
+    let url = match Url::parse(path) {
+        Ok(url) => url,
+        Err(ParseError::RelativeUrlWithoutBase) => {
+            return Ok(format!("{}/{}", SPOTIFY_URL, path.trim_start_matches('/')));
+        }
+        Err(_) => return Err(RawRequestError::ForeignUrl(path.to_owned())),
+    };
+
+    // the parts of SPOTIFY_URL, compared exactly so lookalike hosts and
+    // credentials or ports smuggled into the authority are refused
+    let on_api = url.scheme() == "https"
+        && url.username().is_empty()
+        && url.password().is_none()
+        && url.host_str() == Some("api.spotify.com")
+        && url.port().is_none()
+        && (url.path() == "/v1" || url.path().starts_with("/v1/"));
+
+    match on_api {
+        true => Ok(path.to_owned()),
+        false => Err(RawRequestError::ForeignUrl(path.to_owned())),
+    }
+}
+
+/// Appends the parameters that are set to `url` as a query string.
+fn with_query(url: String, params: &[(&str, Option<&str>)]) -> String {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    for (key, value) in params {
+        if let Some(value) = value {
+            query.append_pair(key, value);
+        }
+    }
+
+    let query = query.finish();
+    match query.len() {
+        0 => url,
+        _ => format!("{}?{}", url, query),
+    }
+}
+
 pub struct SpotifyRequest {
     raxios: RaxiosClient,
 }
@@ -25,6 +148,7 @@ impl SpotifyRequest {
         &self,
         title: &str,
         artist: &str,
+        market: Option<Market>,
     ) -> Result<SearchTrackResponse, Error> {
         info!("Making search request for {} by {}", title, artist);
 
@@ -32,6 +156,10 @@ impl SpotifyRequest {
             "{}/search?type=track&q=track:{} artist:{}",
             SPOTIFY_URL, title, artist
         );
+        let url = match market {
+            Some(market) => format!("{}&market={}", url, market),
+            None => url,
+        };
 
         let data = self.raxios.get::<SearchTrackResponse>(&url, None).await?;
         Ok(data)
@@ -40,13 +168,20 @@ impl SpotifyRequest {
     pub async fn make_playlist_request(
         &self,
         playlist_id: &str,
+        market: Option<Market>,
         link: Option<&String>,
     ) -> Result<PlaylistTracksResponse, Error> {
         info!("Fetching playlist {}", playlist_id);
 
         let url = match link {
             Some(link) => link.to_owned(),
-            None => format!("{}/playlists/{}/tracks", SPOTIFY_URL, playlist_id),
+            None => with_query(
+                format!("{}/playlists/{}/tracks", SPOTIFY_URL, playlist_id),
+                &[
+                    ("market", market.as_ref().map(Market::as_str)),
+                    ("additional_types", Some("track,episode")),
+                ],
+            ),
         };
 
         let data = self
@@ -56,36 +191,483 @@ impl SpotifyRequest {
         Ok(data)
     }
 
+    pub async fn make_full_playlist_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        playlist_id: &str,
+        fields: Option<&FieldsFilter>,
+        market: Option<Market>,
+        additional_types: Option<&[AdditionalType]>,
+    ) -> Result<T, Error> {
+        info!("Fetching playlist details {}", playlist_id);
+
+        let fields = fields.map(|fields| fields.to_string());
+        let additional_types = additional_types.map(|types| {
+            types
+                .iter()
+                .map(|kind| kind.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        });
+        let url = with_query(
+            format!("{}/playlists/{}", SPOTIFY_URL, playlist_id),
+            &[
+                ("fields", fields.as_deref()),
+                ("market", market.as_ref().map(Market::as_str)),
+                ("additional_types", additional_types.as_deref()),
+            ],
+        );
+
+        let data = self.raxios.get::<T>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_playlist_items_fields_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        playlist_id: &str,
+        fields: &FieldsFilter,
+        market: Option<Market>,
+        link: Option<&String>,
+    ) -> Result<PartialPage<T>, Error> {
+        info!("Fetching playlist {} with fields {}", playlist_id, fields);
+
+        // `next` has to be requested too, or paging stops after one page
+        let fields = FieldsFilter::new()
+            .field("next")
+            .nested("items", fields.clone())
+            .to_string();
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/playlists/{}/tracks", SPOTIFY_URL, playlist_id),
+                &[
+                    ("fields", Some(fields.as_str())),
+                    ("market", market.as_ref().map(Market::as_str)),
+                    ("additional_types", Some("track,episode")),
+                ],
+            ),
+        };
+
+        let data = self.raxios.get::<PartialPage<T>>(&url, None).await?;
+        Ok(data)
+    }
+
     pub async fn make_album_request(
         &self,
         album_id: &str,
+        market: Option<Market>,
         link: Option<&String>,
     ) -> Result<AlbumTracksResponse, Error> {
         info!("Fetching album {}", album_id);
 
         let url = match link {
             Some(link) => link.to_owned(),
-            None => format!("{}/albums/{}/tracks", SPOTIFY_URL, album_id),
+            None => with_query(
+                format!("{}/albums/{}/tracks", SPOTIFY_URL, album_id),
+                &[("market", market.as_ref().map(Market::as_str))],
+            ),
         };
 
         let data = self.raxios.get::<AlbumTracksResponse>(&url, None).await?;
         Ok(data)
     }
 
+    pub async fn make_track_request(
+        &self,
+        track_id: &str,
+        market: Option<Market>,
+    ) -> Result<Option<FullTrack>, Error> {
+        info!("Fetching track {}", track_id);
+
+        let url = match market {
+            Some(market) => format!("{}/tracks/{}?market={}", SPOTIFY_URL, track_id, market),
+            None => format!("{}/tracks/{}", SPOTIFY_URL, track_id),
+        };
+
+        let data = self.raxios.get_found::<FullTrack>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_tracks_request(
+        &self,
+        track_ids: &[TrackId],
+        market: Option<Market>,
+    ) -> Result<TracksResponse, Error> {
+        info!("Fetching {} tracks", track_ids.len());
+
+        let ids = join_ids(track_ids);
+        let url = match market {
+            Some(market) => format!("{}/tracks?ids={}&market={}", SPOTIFY_URL, ids, market),
+            None => format!("{}/tracks?ids={}", SPOTIFY_URL, ids),
+        };
+
+        let data = self.raxios.get::<TracksResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_single_album_request(
+        &self,
+        album_id: &str,
+        market: Option<Market>,
+    ) -> Result<Option<FullAlbum>, Error> {
+        info!("Fetching album details {}", album_id);
+
+        let url = match market {
+            Some(market) => format!("{}/albums/{}?market={}", SPOTIFY_URL, album_id, market),
+            None => format!("{}/albums/{}", SPOTIFY_URL, album_id),
+        };
+
+        let data = self.raxios.get_found::<FullAlbum>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_albums_request(
+        &self,
+        album_ids: &[AlbumId],
+        market: Option<Market>,
+    ) -> Result<AlbumsResponse, Error> {
+        info!("Fetching {} albums", album_ids.len());
+
+        let ids = join_ids(album_ids);
+        let url = match market {
+            Some(market) => format!("{}/albums?ids={}&market={}", SPOTIFY_URL, ids, market),
+            None => format!("{}/albums?ids={}", SPOTIFY_URL, ids),
+        };
+
+        let data = self.raxios.get::<AlbumsResponse>(&url, None).await?;
+        Ok(data)
+    }
+
     pub async fn make_audio_features_request(
         &self,
-        track_ids: &[String],
+        track_ids: &[TrackId],
     ) -> Result<AudioFeaturesResponse, Error> {
         info!("Fetching features for {} tracks", track_ids.len());
 
         //
-        let ids = track_ids.join(",");
+        let ids = join_ids(track_ids);
         let url = format!("{}/audio-features?ids={}", SPOTIFY_URL, ids);
 
         let data = self.raxios.get::<AudioFeaturesResponse>(&url, None).await?;
         Ok(data)
     }
 
+    pub async fn make_audio_analysis_request(
+        &self,
+        track_id: &str,
+    ) -> Result<Option<AudioAnalysis>, Error> {
+        info!("Fetching audio analysis for {}", track_id);
+
+        let url = format!("{}/audio-analysis/{}", SPOTIFY_URL, track_id);
+
+        let data = self.raxios.get_found::<AudioAnalysis>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_recommendations_request(
+        &self,
+        options: &RecommendationsOptions,
+    ) -> Result<Recommendations, Error> {
+        info!(
+            "Fetching recommendations for {} seeds",
+            options.seed_count()
+        );
+
+        let url = format!("{}/recommendations?{}", SPOTIFY_URL, options.to_query());
+
+        let data = self.raxios.get::<Recommendations>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_genre_seeds_request(&self) -> Result<GenreSeedsResponse, Error> {
+        info!("Fetching available genre seeds");
+
+        let url = format!("{}/recommendations/available-genre-seeds", SPOTIFY_URL);
+
+        let data = self.raxios.get::<GenreSeedsResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_new_releases_request(
+        &self,
+        country: Option<Country>,
+        link: Option<&String>,
+    ) -> Result<NewReleasesResponse, Error> {
+        info!("Fetching new releases");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/browse/new-releases", SPOTIFY_URL),
+                &[("country", country.as_ref().map(Country::as_str))],
+            ),
+        };
+
+        let data = self.raxios.get::<NewReleasesResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_featured_playlists_request(
+        &self,
+        country: Option<Country>,
+        locale: Option<&str>,
+        link: Option<&String>,
+    ) -> Result<BrowsePlaylistsResponse, Error> {
+        info!("Fetching featured playlists");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/browse/featured-playlists", SPOTIFY_URL),
+                &[
+                    ("country", country.as_ref().map(Country::as_str)),
+                    ("locale", locale),
+                ],
+            ),
+        };
+
+        let data = self
+            .raxios
+            .get::<BrowsePlaylistsResponse>(&url, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_categories_request(
+        &self,
+        country: Option<Country>,
+        locale: Option<&str>,
+        link: Option<&String>,
+    ) -> Result<CategoriesResponse, Error> {
+        info!("Fetching browse categories");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/browse/categories", SPOTIFY_URL),
+                &[
+                    ("country", country.as_ref().map(Country::as_str)),
+                    ("locale", locale),
+                ],
+            ),
+        };
+
+        let data = self.raxios.get::<CategoriesResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_category_request(
+        &self,
+        category_id: &str,
+        country: Option<Country>,
+        locale: Option<&str>,
+    ) -> Result<Option<Category>, Error> {
+        info!("Fetching category {}", category_id);
+
+        let url = with_query(
+            format!("{}/browse/categories/{}", SPOTIFY_URL, category_id),
+            &[
+                ("country", country.as_ref().map(Country::as_str)),
+                ("locale", locale),
+            ],
+        );
+
+        let data = self.raxios.get_found::<Category>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_category_playlists_request(
+        &self,
+        category_id: &str,
+        country: Option<Country>,
+        link: Option<&String>,
+    ) -> Result<BrowsePlaylistsResponse, Error> {
+        info!("Fetching playlists for category {}", category_id);
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!(
+                    "{}/browse/categories/{}/playlists",
+                    SPOTIFY_URL, category_id
+                ),
+                &[("country", country.as_ref().map(Country::as_str))],
+            ),
+        };
+
+        let data = self
+            .raxios
+            .get::<BrowsePlaylistsResponse>(&url, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_show_request(
+        &self,
+        show_id: &str,
+        market: Option<Market>,
+    ) -> Result<Option<Show>, Error> {
+        info!("Fetching show {}", show_id);
+
+        let url = with_query(
+            format!("{}/shows/{}", SPOTIFY_URL, show_id),
+            &[("market", market.as_ref().map(Market::as_str))],
+        );
+
+        let data = self.raxios.get_found::<Show>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_show_episodes_request(
+        &self,
+        show_id: &str,
+        market: Option<Market>,
+        link: Option<&String>,
+    ) -> Result<ShowEpisodesResponse, Error> {
+        info!("Fetching episodes of show {}", show_id);
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/shows/{}/episodes", SPOTIFY_URL, show_id),
+                &[("market", market.as_ref().map(Market::as_str))],
+            ),
+        };
+
+        let data = self.raxios.get::<ShowEpisodesResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_episode_request(
+        &self,
+        episode_id: &str,
+        market: Option<Market>,
+    ) -> Result<Option<Episode>, Error> {
+        info!("Fetching episode {}", episode_id);
+
+        let url = with_query(
+            format!("{}/episodes/{}", SPOTIFY_URL, episode_id),
+            &[("market", market.as_ref().map(Market::as_str))],
+        );
+
+        let data = self.raxios.get_found::<Episode>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_episodes_request(
+        &self,
+        episode_ids: &[EpisodeId],
+        market: Option<Market>,
+    ) -> Result<EpisodesResponse, Error> {
+        info!("Fetching {} episodes", episode_ids.len());
+
+        let ids = join_ids(episode_ids);
+        let url = with_query(
+            format!("{}/episodes", SPOTIFY_URL),
+            &[
+                ("ids", Some(ids.as_str())),
+                ("market", market.as_ref().map(Market::as_str)),
+            ],
+        );
+
+        let data = self.raxios.get::<EpisodesResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_audiobook_request(
+        &self,
+        audiobook_id: &str,
+        market: Option<Market>,
+    ) -> Result<Option<Audiobook>, Error> {
+        info!("Fetching audiobook {}", audiobook_id);
+
+        let url = with_query(
+            format!("{}/audiobooks/{}", SPOTIFY_URL, audiobook_id),
+            &[("market", market.as_ref().map(Market::as_str))],
+        );
+
+        let data = self.raxios.get_found::<Audiobook>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_audiobooks_request(
+        &self,
+        audiobook_ids: &[AudiobookId],
+        market: Option<Market>,
+    ) -> Result<AudiobooksResponse, Error> {
+        info!("Fetching {} audiobooks", audiobook_ids.len());
+
+        let ids = join_ids(audiobook_ids);
+        let url = with_query(
+            format!("{}/audiobooks", SPOTIFY_URL),
+            &[
+                ("ids", Some(ids.as_str())),
+                ("market", market.as_ref().map(Market::as_str)),
+            ],
+        );
+
+        let data = self.raxios.get::<AudiobooksResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_audiobook_chapters_request(
+        &self,
+        audiobook_id: &str,
+        market: Option<Market>,
+        link: Option<&String>,
+    ) -> Result<AudiobookChaptersResponse, Error> {
+        info!("Fetching chapters of audiobook {}", audiobook_id);
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/audiobooks/{}/chapters", SPOTIFY_URL, audiobook_id),
+                &[("market", market.as_ref().map(Market::as_str))],
+            ),
+        };
+
+        let data = self
+            .raxios
+            .get::<AudiobookChaptersResponse>(&url, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_chapter_request(
+        &self,
+        chapter_id: &str,
+        market: Option<Market>,
+    ) -> Result<Option<Chapter>, Error> {
+        info!("Fetching chapter {}", chapter_id);
+
+        let url = with_query(
+            format!("{}/chapters/{}", SPOTIFY_URL, chapter_id),
+            &[("market", market.as_ref().map(Market::as_str))],
+        );
+
+        let data = self.raxios.get_found::<Chapter>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_chapters_request(
+        &self,
+        chapter_ids: &[ChapterId],
+        market: Option<Market>,
+    ) -> Result<ChaptersResponse, Error> {
+        info!("Fetching {} chapters", chapter_ids.len());
+
+        let ids = join_ids(chapter_ids);
+        let url = with_query(
+            format!("{}/chapters", SPOTIFY_URL),
+            &[
+                ("ids", Some(ids.as_str())),
+                ("market", market.as_ref().map(Market::as_str)),
+            ],
+        );
+
+        let data = self.raxios.get::<ChaptersResponse>(&url, None).await?;
+        Ok(data)
+    }
+
     pub async fn make_user_playlist_request(
         &self,
         link: Option<&String>,
@@ -101,15 +683,35 @@ impl SpotifyRequest {
         Ok(data)
     }
 
+    pub async fn make_other_user_playlist_request(
+        &self,
+        user_id: &str,
+        link: Option<&String>,
+    ) -> Result<UserPlaylistResponse, Error> {
+        info!("Fetching playlists of user {}", user_id);
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => format!("{}/users/{}/playlists", SPOTIFY_URL, user_id),
+        };
+
+        let data = self.raxios.get::<UserPlaylistResponse>(&url, None).await?;
+        Ok(data)
+    }
+
     pub async fn make_user_album_request(
         &self,
+        market: Option<Market>,
         link: Option<&String>,
     ) -> Result<UserAlbumResponse, Error> {
         info!("Fetching user albums");
 
         let url = match link {
             Some(link) => link.to_owned(),
-            None => format!("{}/me/albums", SPOTIFY_URL),
+            None => with_query(
+                format!("{}/me/albums", SPOTIFY_URL),
+                &[("market", market.as_ref().map(Market::as_str))],
+            ),
         };
 
         let data = self.raxios.get::<UserAlbumResponse>(&url, None).await?;
@@ -118,16 +720,758 @@ impl SpotifyRequest {
 
     pub async fn make_user_saved_song_request(
         &self,
+        market: Option<Market>,
         link: Option<&String>,
     ) -> Result<SavedTrackResponse, Error> {
         info!("Fetching user saved songs");
 
         let url = match link {
             Some(link) => link.to_owned(),
-            None => format!("{}/me/tracks", SPOTIFY_URL),
+            None => with_query(
+                format!("{}/me/tracks", SPOTIFY_URL),
+                &[("market", market.as_ref().map(Market::as_str))],
+            ),
         };
 
         let data = self.raxios.get::<SavedTrackResponse>(&url, None).await?;
         Ok(data)
     }
+
+    pub async fn make_user_show_request(
+        &self,
+        link: Option<&String>,
+    ) -> Result<UserShowResponse, Error> {
+        info!("Fetching user saved shows");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => format!("{}/me/shows", SPOTIFY_URL),
+        };
+
+        let data = self.raxios.get::<UserShowResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_user_episode_request(
+        &self,
+        link: Option<&String>,
+    ) -> Result<UserEpisodeResponse, Error> {
+        info!("Fetching user saved episodes");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => format!("{}/me/episodes", SPOTIFY_URL),
+        };
+
+        let data = self.raxios.get::<UserEpisodeResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_user_audiobook_request(
+        &self,
+        link: Option<&String>,
+    ) -> Result<UserAudiobookResponse, Error> {
+        info!("Fetching user saved audiobooks");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => format!("{}/me/audiobooks", SPOTIFY_URL),
+        };
+
+        let data = self.raxios.get::<UserAudiobookResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_save_library_request<T: Id>(
+        &self,
+        kind: LibraryKind,
+        ids: &[T],
+    ) -> Result<(), Error> {
+        info!("Saving {} {}", ids.len(), kind.path());
+
+        let url = format!("{}/me/{}?ids={}", SPOTIFY_URL, kind.path(), join_ids(ids));
+
+        self.raxios.put(&url, None, None).await
+    }
+
+    pub async fn make_remove_library_request<T: Id>(
+        &self,
+        kind: LibraryKind,
+        ids: &[T],
+    ) -> Result<(), Error> {
+        info!("Removing {} {}", ids.len(), kind.path());
+
+        let url = format!("{}/me/{}?ids={}", SPOTIFY_URL, kind.path(), join_ids(ids));
+
+        self.raxios.delete(&url, None, None).await
+    }
+
+    pub async fn make_check_library_request<T: Id>(
+        &self,
+        kind: LibraryKind,
+        ids: &[T],
+    ) -> Result<Vec<bool>, Error> {
+        info!("Checking {} saved {}", ids.len(), kind.path());
+
+        let url = format!(
+            "{}/me/{}/contains?ids={}",
+            SPOTIFY_URL,
+            kind.path(),
+            join_ids(ids)
+        );
+
+        let data = self.raxios.get::<Vec<bool>>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_playback_state_request(
+        &self,
+        market: Option<Market>,
+    ) -> Result<Option<PlaybackState>, Error> {
+        info!("Fetching playback state");
+
+        let url = with_query(
+            format!("{}/me/player", SPOTIFY_URL),
+            &[
+                ("market", market.as_ref().map(Market::as_str)),
+                ("additional_types", Some("track,episode")),
+            ],
+        );
+
+        let data = self
+            .raxios
+            .get_optional::<PlaybackState>(&url, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_currently_playing_request(
+        &self,
+        market: Option<Market>,
+    ) -> Result<Option<CurrentlyPlaying>, Error> {
+        info!("Fetching currently playing item");
+
+        let url = with_query(
+            format!("{}/me/player/currently-playing", SPOTIFY_URL),
+            &[
+                ("market", market.as_ref().map(Market::as_str)),
+                ("additional_types", Some("track,episode")),
+            ],
+        );
+
+        let data = self
+            .raxios
+            .get_optional::<CurrentlyPlaying>(&url, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_devices_request(&self) -> Result<DevicesResponse, Error> {
+        info!("Fetching available devices");
+
+        let url = format!("{}/me/player/devices", SPOTIFY_URL);
+
+        let data = self.raxios.get::<DevicesResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_transfer_playback_request(
+        &self,
+        device_id: &str,
+        play: Option<bool>,
+    ) -> Result<(), Error> {
+        info!("Transferring playback to {}", device_id);
+
+        let url = format!("{}/me/player", SPOTIFY_URL);
+        let body = match play {
+            Some(play) => json!({ "device_ids": [device_id], "play": play }),
+            None => json!({ "device_ids": [device_id] }),
+        };
+
+        self.raxios.put(&url, Some(&body), None).await
+    }
+
+    pub async fn make_play_request(
+        &self,
+        device_id: Option<&str>,
+        options: &PlayOptions,
+    ) -> Result<(), Error> {
+        info!("Starting playback");
+
+        let url = with_query(
+            format!("{}/me/player/play", SPOTIFY_URL),
+            &[("device_id", device_id)],
+        );
+
+        self.raxios
+            .put(&url, options.to_body().as_ref(), None)
+            .await
+    }
+
+    pub async fn make_pause_request(&self, device_id: Option<&str>) -> Result<(), Error> {
+        info!("Pausing playback");
+
+        let url = with_query(
+            format!("{}/me/player/pause", SPOTIFY_URL),
+            &[("device_id", device_id)],
+        );
+
+        self.raxios.put(&url, None, None).await
+    }
+
+    pub async fn make_next_request(&self, device_id: Option<&str>) -> Result<(), Error> {
+        info!("Skipping to next item");
+
+        let url = with_query(
+            format!("{}/me/player/next", SPOTIFY_URL),
+            &[("device_id", device_id)],
+        );
+
+        self.raxios.post(&url, None, None).await
+    }
+
+    pub async fn make_previous_request(&self, device_id: Option<&str>) -> Result<(), Error> {
+        info!("Skipping to previous item");
+
+        let url = with_query(
+            format!("{}/me/player/previous", SPOTIFY_URL),
+            &[("device_id", device_id)],
+        );
+
+        self.raxios.post(&url, None, None).await
+    }
+
+    pub async fn make_seek_request(
+        &self,
+        position_ms: u64,
+        device_id: Option<&str>,
+    ) -> Result<(), Error> {
+        info!("Seeking to {}ms", position_ms);
+
+        let position_ms = position_ms.to_string();
+        let url = with_query(
+            format!("{}/me/player/seek", SPOTIFY_URL),
+            &[
+                ("position_ms", Some(position_ms.as_str())),
+                ("device_id", device_id),
+            ],
+        );
+
+        self.raxios.put(&url, None, None).await
+    }
+
+    pub async fn make_repeat_request(
+        &self,
+        state: RepeatState,
+        device_id: Option<&str>,
+    ) -> Result<(), Error> {
+        info!("Setting repeat mode to {}", state.as_str());
+
+        let url = with_query(
+            format!("{}/me/player/repeat", SPOTIFY_URL),
+            &[("state", Some(state.as_str())), ("device_id", device_id)],
+        );
+
+        self.raxios.put(&url, None, None).await
+    }
+
+    pub async fn make_shuffle_request(
+        &self,
+        state: bool,
+        device_id: Option<&str>,
+    ) -> Result<(), Error> {
+        info!("Setting shuffle to {}", state);
+
+        let state = state.to_string();
+        let url = with_query(
+            format!("{}/me/player/shuffle", SPOTIFY_URL),
+            &[("state", Some(state.as_str())), ("device_id", device_id)],
+        );
+
+        self.raxios.put(&url, None, None).await
+    }
+
+    pub async fn make_volume_request(
+        &self,
+        volume_percent: u8,
+        device_id: Option<&str>,
+    ) -> Result<(), Error> {
+        info!("Setting volume to {}%", volume_percent);
+
+        let volume_percent = volume_percent.to_string();
+        let url = with_query(
+            format!("{}/me/player/volume", SPOTIFY_URL),
+            &[
+                ("volume_percent", Some(volume_percent.as_str())),
+                ("device_id", device_id),
+            ],
+        );
+
+        self.raxios.put(&url, None, None).await
+    }
+
+    pub async fn make_add_to_queue_request(
+        &self,
+        item: &PlayableId,
+        device_id: Option<&str>,
+    ) -> Result<(), Error> {
+        info!("Adding {} to queue", item);
+
+        let uri = item.uri();
+        let url = with_query(
+            format!("{}/me/player/queue", SPOTIFY_URL),
+            &[("uri", Some(uri.as_str())), ("device_id", device_id)],
+        );
+
+        self.raxios.post(&url, None, None).await
+    }
+
+    pub async fn make_queue_request(&self) -> Result<Queue, Error> {
+        info!("Fetching play queue");
+
+        let url = format!("{}/me/player/queue", SPOTIFY_URL);
+
+        let data = self.raxios.get::<Queue>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_recently_played_request(
+        &self,
+        cursor: Option<PlayedCursor>,
+        link: Option<&String>,
+    ) -> Result<RecentlyPlayedResponse, Error> {
+        info!("Fetching recently played tracks");
+
+        let (after, before) = match cursor {
+            Some(PlayedCursor::After(timestamp)) => (Some(timestamp.to_string()), None),
+            Some(PlayedCursor::Before(timestamp)) => (None, Some(timestamp.to_string())),
+            None => (None, None),
+        };
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/me/player/recently-played", SPOTIFY_URL),
+                &[
+                    ("limit", Some("50")),
+                    ("after", after.as_deref()),
+                    ("before", before.as_deref()),
+                ],
+            ),
+        };
+
+        let data = self
+            .raxios
+            .get::<RecentlyPlayedResponse>(&url, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_top_artists_request(
+        &self,
+        time_range: Option<TimeRange>,
+        link: Option<&String>,
+    ) -> Result<TopArtistsResponse, Error> {
+        info!("Fetching user top artists");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/me/top/artists", SPOTIFY_URL),
+                &[
+                    ("limit", Some("50")),
+                    ("time_range", time_range.map(|range| range.as_str())),
+                ],
+            ),
+        };
+
+        let data = self.raxios.get::<TopArtistsResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_top_tracks_request(
+        &self,
+        time_range: Option<TimeRange>,
+        link: Option<&String>,
+    ) -> Result<TopTracksResponse, Error> {
+        info!("Fetching user top tracks");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => with_query(
+                format!("{}/me/top/tracks", SPOTIFY_URL),
+                &[
+                    ("limit", Some("50")),
+                    ("time_range", time_range.map(|range| range.as_str())),
+                ],
+            ),
+        };
+
+        let data = self.raxios.get::<TopTracksResponse>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_create_playlist_request(
+        &self,
+        user_id: &str,
+        details: &PlaylistDetails,
+    ) -> Result<UserPlaylist, Error> {
+        info!("Creating playlist for user {}", user_id);
+
+        let url = format!("{}/users/{}/playlists", SPOTIFY_URL, user_id);
+        let body = details.to_body();
+
+        let data = self
+            .raxios
+            .send::<UserPlaylist>(Method::POST, &url, Some(&body), None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_change_playlist_details_request(
+        &self,
+        playlist_id: &str,
+        details: &PlaylistDetails,
+    ) -> Result<(), Error> {
+        info!("Changing details of playlist {}", playlist_id);
+
+        let url = format!("{}/playlists/{}", SPOTIFY_URL, playlist_id);
+        let body = details.to_body();
+
+        self.raxios.put(&url, Some(&body), None).await
+    }
+
+    pub async fn make_add_items_request(
+        &self,
+        playlist_id: &str,
+        items: &[PlayableId],
+        position: Option<u32>,
+    ) -> Result<SnapshotResponse, Error> {
+        info!("Adding {} items to playlist {}", items.len(), playlist_id);
+
+        let url = format!("{}/playlists/{}/tracks", SPOTIFY_URL, playlist_id);
+        let uris: Vec<String> = items.iter().map(PlayableId::uri).collect();
+        let body = match position {
+            Some(position) => json!({ "uris": uris, "position": position }),
+            None => json!({ "uris": uris }),
+        };
+
+        let data = self
+            .raxios
+            .send::<SnapshotResponse>(Method::POST, &url, Some(&body), None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_remove_items_request(
+        &self,
+        playlist_id: &str,
+        items: &[RemoveItem],
+        snapshot_id: Option<&str>,
+    ) -> Result<SnapshotResponse, Error> {
+        info!(
+            "Removing {} items from playlist {}",
+            items.len(),
+            playlist_id
+        );
+
+        let url = format!("{}/playlists/{}/tracks", SPOTIFY_URL, playlist_id);
+        let tracks: Vec<_> = items.iter().map(|item| item.to_json()).collect();
+        let body = match snapshot_id {
+            Some(snapshot_id) => json!({ "tracks": tracks, "snapshot_id": snapshot_id }),
+            None => json!({ "tracks": tracks }),
+        };
+
+        let data = self
+            .raxios
+            .send::<SnapshotResponse>(Method::DELETE, &url, Some(&body), None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_reorder_items_request(
+        &self,
+        playlist_id: &str,
+        range_start: u32,
+        range_length: u32,
+        insert_before: u32,
+        snapshot_id: Option<&str>,
+    ) -> Result<SnapshotResponse, Error> {
+        info!("Reordering items in playlist {}", playlist_id);
+
+        let url = format!("{}/playlists/{}/tracks", SPOTIFY_URL, playlist_id);
+        let mut body = json!({
+            "range_start": range_start,
+            "range_length": range_length,
+            "insert_before": insert_before,
+        });
+        if let Some(snapshot_id) = snapshot_id {
+            body["snapshot_id"] = json!(snapshot_id);
+        }
+
+        let data = self
+            .raxios
+            .send::<SnapshotResponse>(Method::PUT, &url, Some(&body), None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_replace_items_request(
+        &self,
+        playlist_id: &str,
+        items: &[PlayableId],
+    ) -> Result<SnapshotResponse, Error> {
+        info!("Replacing items in playlist {}", playlist_id);
+
+        let url = format!("{}/playlists/{}/tracks", SPOTIFY_URL, playlist_id);
+        let uris: Vec<String> = items.iter().map(PlayableId::uri).collect();
+        let body = json!({ "uris": uris });
+
+        let data = self
+            .raxios
+            .send::<SnapshotResponse>(Method::PUT, &url, Some(&body), None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_playlist_cover_image_request(
+        &self,
+        playlist_id: &str,
+    ) -> Result<Vec<Image>, Error> {
+        info!("Fetching cover image of playlist {}", playlist_id);
+
+        let url = format!("{}/playlists/{}/images", SPOTIFY_URL, playlist_id);
+
+        let data = self.raxios.get::<Vec<Image>>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_upload_playlist_cover_image_request(
+        &self,
+        playlist_id: &str,
+        image: String,
+    ) -> Result<(), Error> {
+        info!("Uploading cover image for playlist {}", playlist_id);
+
+        let url = format!("{}/playlists/{}/images", SPOTIFY_URL, playlist_id);
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("image/jpeg"),
+        );
+
+        self.raxios.put_data(&url, image, Some(headers)).await
+    }
+
+    pub async fn make_followed_artists_request(
+        &self,
+        link: Option<&String>,
+    ) -> Result<FollowedArtistsResponse, Error> {
+        info!("Fetching followed artists");
+
+        let url = match link {
+            Some(link) => link.to_owned(),
+            None => format!("{}/me/following?type=artist&limit=50", SPOTIFY_URL),
+        };
+
+        let data = self
+            .raxios
+            .get::<FollowedArtistsResponse>(&url, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_follow_request<T: Id>(
+        &self,
+        kind: FollowKind,
+        ids: &[T],
+    ) -> Result<(), Error> {
+        info!("Following {} {}s", ids.len(), kind.as_str());
+
+        let url = format!(
+            "{}/me/following?type={}&ids={}",
+            SPOTIFY_URL,
+            kind.as_str(),
+            join_ids(ids)
+        );
+
+        self.raxios.put(&url, None, None).await
+    }
+
+    pub async fn make_unfollow_request<T: Id>(
+        &self,
+        kind: FollowKind,
+        ids: &[T],
+    ) -> Result<(), Error> {
+        info!("Unfollowing {} {}s", ids.len(), kind.as_str());
+
+        let url = format!(
+            "{}/me/following?type={}&ids={}",
+            SPOTIFY_URL,
+            kind.as_str(),
+            join_ids(ids)
+        );
+
+        self.raxios.delete(&url, None, None).await
+    }
+
+    pub async fn make_check_following_request<T: Id>(
+        &self,
+        kind: FollowKind,
+        ids: &[T],
+    ) -> Result<Vec<bool>, Error> {
+        info!("Checking {} followed {}s", ids.len(), kind.as_str());
+
+        let url = format!(
+            "{}/me/following/contains?type={}&ids={}",
+            SPOTIFY_URL,
+            kind.as_str(),
+            join_ids(ids)
+        );
+
+        let data = self.raxios.get::<Vec<bool>>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_follow_playlist_request(
+        &self,
+        playlist_id: &str,
+        public: bool,
+    ) -> Result<(), Error> {
+        info!("Following playlist {}", playlist_id);
+
+        let url = format!("{}/playlists/{}/followers", SPOTIFY_URL, playlist_id);
+        let body = json!({ "public": public });
+
+        self.raxios.put(&url, Some(&body), None).await
+    }
+
+    pub async fn make_unfollow_playlist_request(&self, playlist_id: &str) -> Result<(), Error> {
+        info!("Unfollowing playlist {}", playlist_id);
+
+        let url = format!("{}/playlists/{}/followers", SPOTIFY_URL, playlist_id);
+
+        self.raxios.delete(&url, None, None).await
+    }
+
+    pub async fn make_check_playlist_followers_request(
+        &self,
+        playlist_id: &str,
+        user_ids: &[UserId],
+    ) -> Result<Vec<bool>, Error> {
+        info!(
+            "Checking if {} users follow playlist {}",
+            user_ids.len(),
+            playlist_id
+        );
+
+        let url = format!(
+            "{}/playlists/{}/followers/contains?ids={}",
+            SPOTIFY_URL,
+            playlist_id,
+            join_ids(user_ids)
+        );
+
+        let data = self.raxios.get::<Vec<bool>>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_current_user_request(&self) -> Result<CurrentUser, Error> {
+        info!("Fetching current user profile");
+
+        let url = format!("{}/me", SPOTIFY_URL);
+
+        let data = self.raxios.get::<CurrentUser>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_user_request(&self, user_id: &str) -> Result<Option<User>, Error> {
+        info!("Fetching user profile {}", user_id);
+
+        let url = format!("{}/users/{}", SPOTIFY_URL, user_id);
+
+        let data = self.raxios.get_found::<User>(&url, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_raw_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<T, RawRequestError> {
+        info!("Making raw {} request to {}", method, path);
+
+        let url = raw_url(path)?;
+
+        let data = self.raxios.send::<T>(method, &url, body, None).await?;
+        Ok(data)
+    }
+
+    pub async fn make_optional_raw_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Option<T>, RawRequestError> {
+        info!("Making raw {} request to {}", method, path);
+
+        let url = raw_url(path)?;
+
+        let data = self
+            .raxios
+            .send_optional::<T>(method, &url, body, None)
+            .await?;
+        Ok(data)
+    }
+
+    pub async fn make_markets_request(&self) -> Result<MarketsResponse, Error> {
+        info!("Fetching available markets");
+
+        let url = format!("{}/markets", SPOTIFY_URL);
+
+        let data = self.raxios.get::<MarketsResponse>(&url, None).await?;
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_raw_paths_against_the_api() {
+        let expected = "https://api.spotify.com/v1/me/shows?limit=5";
+        assert_eq!(raw_url("me/shows?limit=5").unwrap(), expected);
+        assert_eq!(raw_url("/me/shows?limit=5").unwrap(), expected);
+        assert_eq!(raw_url(expected).unwrap(), expected);
+    }
+
+    #[test]
+    fn keeps_urls_in_relative_queries_relative() {
+        let path = "search?q=https://open.spotify.com/track/1&type=track";
+        assert_eq!(raw_url(path).unwrap(), format!("{}/{}", SPOTIFY_URL, path));
+    }
+
+    #[test]
+    fn refuses_raw_urls_off_the_api() {
+        let foreign = [
+            "https://example.com/v1/me",
+            "http://api.spotify.com/v1/me",
+            "https://api.spotify.com/v10/me",
+            "https://api.spotify.com.example.com/v1/me",
+            "https://api.spotify.com@example.com/v1/me",
+            "https://user@api.spotify.com/v1/me",
+            "https://api.spotify.com:8443/v1/me",
+            "https://API.spotify.com.evil/v1/me",
+            "https://api.spotify.com/v1.example/me",
+            "https://accounts.spotify.com/api/token",
+        ];
+
+        for url in &foreign {
+            match raw_url(url) {
+                Err(RawRequestError::ForeignUrl(refused)) => assert_eq!(refused, *url),
+                other => panic!("{} was not refused: {:?}", url, other),
+            }
+        }
+    }
 }
@@ -0,0 +1,166 @@
+//! Reads response fixtures shaped like the payloads spotify sends into the
+//! models. Run with `--features strict` these are contract tests: a key that
+//! a model doesn't know fails the read.
+
+use rspotify_sdk::response::audio_features::{AudioFeatures, Mode, PitchClass};
+use rspotify_sdk::response::audiobook::Chapter;
+use rspotify_sdk::response::player::{PlaybackState, RepeatState};
+use rspotify_sdk::response::playlist::{Playlist, UserPlaylistResponse};
+use rspotify_sdk::response::spotify_types::{
+    Episode, FullAlbum, FullArtist, FullTrack, PlayableItem, ReleaseDate,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+fn fixture(name: &str) -> Value {
+    let path = format!(
+        "{}/tests/fixtures/{}.json",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    let text = std::fs::read_to_string(&path).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+/// Reads a payload into a model, checking that what the model writes back
+/// out reads again into the same thing.
+fn read<T: DeserializeOwned + Serialize>(value: Value) -> T {
+    let model: T = serde_json::from_value(value).unwrap();
+    let written = serde_json::to_value(&model).unwrap();
+    let reread: T = serde_json::from_value(written.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&reread).unwrap(), written);
+    model
+}
+
+/// A fixture with a key no model knows added next to the known ones.
+#[cfg(any(feature = "strict", feature = "extra-fields"))]
+fn with_unknown_key(name: &str) -> Value {
+    let mut value = fixture(name);
+    value["unknown_key"] = Value::Bool(true);
+    value
+}
+
+#[test]
+fn reads_track() {
+    let track: FullTrack = read(fixture("track"));
+    assert_eq!(track.catalog_id(), Some("11dFghVXANMlKmJXsNCbNl"));
+    assert_eq!(track.album.name, "Cut To The Feeling");
+    assert_eq!(track.album.release_date, "2017-05-26".parse().ok());
+    assert_eq!(track.external_ids["isrc"], "USUM71703861");
+}
+
+#[test]
+fn reads_artist() {
+    let artist: FullArtist = read(fixture("artist"));
+    assert_eq!(artist.name, "Pitbull");
+    assert_eq!(artist.followers.total, 10898402);
+}
+
+#[test]
+fn reads_album() {
+    let album: FullAlbum = read(fixture("album"));
+    assert_eq!(
+        album.label.as_deref(),
+        Some("Mr.305/Polo Grounds Music/RCA Records")
+    );
+    assert_eq!(album.tracks.items.len(), 2);
+    assert_eq!(album.tracks.total, 18);
+    assert!(album.tracks.next.is_some());
+}
+
+#[test]
+fn reads_playlist_items() {
+    let playlist: Playlist = read(fixture("playlist"));
+    let items = &playlist.tracks.items;
+    assert_eq!(items.len(), 2);
+
+    let catalog = &items[0];
+    assert_eq!(
+        catalog.added_by.as_ref().map(|user| user.id.as_str()),
+        Some("jmperezperez")
+    );
+    let track = catalog.catalog_track().unwrap();
+    assert_eq!(track.episode, Some(false));
+    assert_eq!(track.track, Some(true));
+
+    let local = &items[1];
+    assert!(local.catalog_track().is_none());
+    assert_eq!(local.local_file().unwrap().title, "First Take");
+}
+
+#[test]
+fn reads_user_playlists() {
+    let page: UserPlaylistResponse = read(fixture("user_playlists"));
+    let playlist = &page.items[0];
+    assert_eq!(playlist.r#type, "playlist");
+    assert_eq!(playlist.tracks.total, 2);
+    assert_eq!(playlist.owner.display_name.as_deref(), Some("jmperezperez"));
+}
+
+#[test]
+fn reads_episode() {
+    let episode: Episode = read(fixture("episode"));
+    assert_eq!(episode.episode.name, "Spotify Wrapped 2020");
+    assert_eq!(episode.show.name, "Spotify: For the Record");
+
+    let item: PlayableItem = serde_json::from_value(fixture("episode")).unwrap();
+    assert!(item.as_episode().is_some());
+}
+
+#[test]
+fn reads_chapter() {
+    let chapter: Chapter = read(fixture("chapter"));
+    assert_eq!(chapter.chapter.chapter_number, 1);
+    assert_eq!(
+        chapter.chapter.release_date,
+        ReleaseDate {
+            year: 0,
+            month: None,
+            day: None
+        }
+    );
+    assert_eq!(chapter.audiobook.narrators.len(), 2);
+}
+
+#[test]
+fn reads_playback_state() {
+    let state: PlaybackState = read(fixture("playback_state"));
+    assert_eq!(state.device.name, "Living Room");
+    assert_eq!(state.repeat_state, RepeatState::Off);
+    assert_eq!(state.smart_shuffle, Some(false));
+    assert_eq!(state.playing.progress_ms, Some(44272));
+    assert!(state.playing.item.unwrap().as_track().is_some());
+}
+
+#[test]
+fn reads_audio_features() {
+    let features: AudioFeatures = read(fixture("audio_features"));
+    assert_eq!(features.key, PitchClass::A);
+    assert_eq!(features.mode, Mode::Minor);
+    assert_eq!(features.musical_key().camelot().as_deref(), Some("8A"));
+}
+
+// Episode, Chapter and PlaybackState flatten another model, so these check
+// that keys next to the flattened fields still reach `strict` and `extra`.
+
+#[cfg(feature = "strict")]
+#[test]
+fn strict_rejects_unknown_keys_around_flattened_models() {
+    assert!(serde_json::from_value::<Episode>(with_unknown_key("episode")).is_err());
+    assert!(serde_json::from_value::<Chapter>(with_unknown_key("chapter")).is_err());
+    assert!(serde_json::from_value::<PlaybackState>(with_unknown_key("playback_state")).is_err());
+}
+
+#[cfg(all(feature = "extra-fields", not(feature = "strict")))]
+#[test]
+fn flattened_models_keep_unknown_keys() {
+    let episode: Episode = read(with_unknown_key("episode"));
+    assert_eq!(episode.episode.extra["unknown_key"], true);
+
+    let chapter: Chapter = read(with_unknown_key("chapter"));
+    assert_eq!(chapter.chapter.extra["unknown_key"], true);
+
+    let state: PlaybackState = read(with_unknown_key("playback_state"));
+    assert_eq!(state.playing.extra["unknown_key"], true);
+}